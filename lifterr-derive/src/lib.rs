@@ -0,0 +1,86 @@
+//! Derive macros for [lifterr](https://docs.rs/lifterr).
+//!
+//! This crate requires Rust 1.71 or newer.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derives `From` impls for every variant annotated with `#[merge]`, making the enum a target for `Merge::merge`.
+///
+/// Each annotated variant must hold exactly one unnamed field, whose type becomes the source of the conversion.
+///
+/// ```
+/// use lifterr::result::Merge;
+/// use lifterr_derive::MergeTarget;
+///
+/// #[derive(Debug, PartialEq, MergeTarget)]
+/// enum Outcome {
+///     #[merge]
+///     Value(i32),
+///     #[merge]
+///     Failure(&'static str),
+///     Unknown,
+/// }
+///
+/// fn merge(r: Result<i32, &'static str>) -> Outcome {
+///     r.merge()
+/// }
+///
+/// assert_eq!(merge(Ok(42)), Outcome::Value(42));
+/// assert_eq!(merge(Err("e")), Outcome::Failure("e"));
+/// ```
+#[proc_macro_derive(MergeTarget, attributes(merge))]
+pub fn derive_merge_target(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                input,
+                "MergeTarget can only be derived for enums",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut impls = Vec::new();
+    for variant in &data.variants {
+        if !variant.attrs.iter().any(|a| a.path().is_ident("merge")) {
+            continue;
+        }
+
+        let field = match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+            _ => {
+                return Err(Error::new_spanned(
+                    variant,
+                    "#[merge] variants must hold exactly one unnamed field",
+                ))
+            }
+        };
+
+        let variant = &variant.ident;
+        let ty = &field.ty;
+        impls.push(quote! {
+            impl #impl_generics ::core::convert::From<#ty> for #name #ty_generics #where_clause {
+                fn from(value: #ty) -> Self {
+                    #name::#variant(value)
+                }
+            }
+        });
+    }
+
+    Ok(quote! { #(#impls)* })
+}
@@ -0,0 +1,87 @@
+//! Conversions between lifterr's branching types and [`Either`].
+//!
+//! This module is only available with the `either` feature enabled.
+//!
+//! Successful or present values always go to the `Left` branch, unlike the `From` impls shipped by the `either`
+//! crate, which favour the `Right` branch for `Ok` values.
+
+use ::either::Either;
+
+/// Lifter of branching values into `Either<L, R>`.
+pub trait LiftEither<L, R> {
+    /// Lifts a value into an `Either<L, R>`, keeping successful or present values on the `Left` branch.
+    ///
+    /// Named so as not to collide with the `either` crate's own `IntoEither::into_either`.
+    ///
+    /// ```
+    /// use either::Either;
+    /// use lifterr::either::{EitherExt, LiftEither};
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).lift_either(), Either::Left(1));
+    /// assert_eq!(Err::<i32, _>("e").lift_either(), Either::Right("e"));
+    /// assert_eq!(Some(1).lift_either(), Either::Left(1));
+    /// assert_eq!(None::<i32>.lift_either(), Either::Right(()));
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).lift_either().into_result(), Ok(1));
+    /// assert_eq!(Err::<i32, _>("e").lift_either().into_result(), Err("e"));
+    /// assert_eq!(Some(1).lift_either().into_option(), Some(1));
+    /// assert_eq!(None::<i32>.lift_either().into_option(), None);
+    /// ```
+    fn lift_either(self) -> Either<L, R>;
+}
+
+impl<A, E> LiftEither<A, E> for Result<A, E> {
+    fn lift_either(self) -> Either<A, E> {
+        match self {
+            Ok(a) => Either::Left(a),
+            Err(e) => Either::Right(e),
+        }
+    }
+}
+
+impl<A> LiftEither<A, ()> for Option<A> {
+    fn lift_either(self) -> Either<A, ()> {
+        match self {
+            Some(a) => Either::Left(a),
+            None => Either::Right(()),
+        }
+    }
+}
+
+/// Extension with a set of conversions from `Either<L, R>` back into results and options.
+pub trait EitherExt<L, R> {
+    /// Turns `Left(l)` into `Ok(l)` or `Right(r)` into `Err(r)`.
+    ///
+    /// ```
+    /// use either::Either;
+    /// use lifterr::either::EitherExt;
+    ///
+    /// assert_eq!(Either::<i32, &str>::Left(1).into_result(), Ok(1));
+    /// assert_eq!(Either::<i32, &str>::Right("e").into_result(), Err("e"));
+    /// ```
+    fn into_result(self) -> Result<L, R>;
+
+    /// Turns `Left(l)` into `Some(l)` or `Right(r)` into `None`, discarding `r`.
+    ///
+    /// ```
+    /// use either::Either;
+    /// use lifterr::either::EitherExt;
+    ///
+    /// assert_eq!(Either::<i32, ()>::Left(1).into_option(), Some(1));
+    /// assert_eq!(Either::<i32, ()>::Right(()).into_option(), None);
+    /// ```
+    fn into_option(self) -> Option<L>;
+}
+
+impl<L, R> EitherExt<L, R> for Either<L, R> {
+    fn into_result(self) -> Result<L, R> {
+        match self {
+            Either::Left(l) => Ok(l),
+            Either::Right(r) => Err(r),
+        }
+    }
+
+    fn into_option(self) -> Option<L> {
+        self.left()
+    }
+}
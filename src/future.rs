@@ -0,0 +1,125 @@
+//! Extra utilities for handling failures in async code.
+//!
+//! This module extends capabilities offered by [`std::future`] and is only available with the `tokio` feature enabled.
+
+use std::future::Future;
+use tokio::sync::mpsc::error::SendError;
+use tokio::sync::mpsc::Sender;
+use tokio::task::JoinError;
+
+/// Extension with a set of extra async combinators for `Result<A, E>`.
+///
+/// This trait is only available with the `tokio` feature enabled, which requires Rust 1.75 or newer.
+pub trait AsyncResultExt<A, E> {
+    /// Recovers from an error of type `E` by spawning the future returned by `f` as a separate task, possibly
+    /// remapping to a different error of type `H`, into which task failures are also converted.
+    ///
+    /// ```
+    /// use lifterr::future::AsyncResultExt;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use tokio::task::JoinError;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error { Fallback, Crashed }
+    ///
+    /// impl From<JoinError> for Error {
+    ///     fn from(_: JoinError) -> Self { Error::Crashed }
+    /// }
+    ///
+    /// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// rt.block_on(async {
+    ///     let spawns = Arc::new(AtomicUsize::new(0));
+    ///     let fallback = |spawns: Arc<AtomicUsize>| move |_: &str| async move {
+    ///         spawns.fetch_add(1, Ordering::SeqCst);
+    ///         Err::<i32, _>(Error::Fallback)
+    ///     };
+    ///
+    ///     assert_eq!(Ok::<_, &str>(1).or_try_concurrent(fallback(spawns.clone())).await, Ok(1));
+    ///     assert_eq!(spawns.load(Ordering::SeqCst), 0);
+    ///
+    ///     assert_eq!(Err::<i32, _>("e").or_try_concurrent(fallback(spawns.clone())).await, Err(Error::Fallback));
+    ///     assert_eq!(spawns.load(Ordering::SeqCst), 1);
+    ///
+    ///     let crashing = Err::<i32, _>("e").or_try_concurrent(|_| async { panic!("crashed") });
+    ///     assert_eq!(crashing.await, Err(Error::Crashed));
+    /// });
+    /// ```
+    fn or_try_concurrent<F, Fut, H>(self, f: F) -> impl Future<Output = Result<A, H>> + Send
+    where
+        F: FnOnce(E) -> Fut + Send,
+        Fut: Future<Output = Result<A, H>> + Send + 'static,
+        A: Send + 'static,
+        E: Send,
+        H: From<JoinError> + Send + 'static;
+
+    /// Sends `A` to `ok_tx` if `Ok(a)` or `E` to `err_tx` if `Err(e)`, waiting for capacity in the chosen channel.
+    ///
+    /// When the chosen channel is closed, the whole result is handed back inside the error.
+    ///
+    /// ```
+    /// use lifterr::future::AsyncResultExt;
+    /// use tokio::sync::mpsc;
+    ///
+    /// let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    ///
+    /// rt.block_on(async {
+    ///     let (ok_tx, mut ok_rx) = mpsc::channel(1);
+    ///     let (err_tx, mut err_rx) = mpsc::channel(1);
+    ///
+    ///     assert!(Ok::<_, &str>(1).route(&ok_tx, &err_tx).await.is_ok());
+    ///     assert_eq!(ok_rx.try_recv().ok(), Some(1));
+    ///     assert!(err_rx.try_recv().is_err());
+    ///
+    ///     assert!(Err::<i32, _>("e").route(&ok_tx, &err_tx).await.is_ok());
+    ///     assert_eq!(err_rx.try_recv().ok(), Some("e"));
+    ///     assert!(ok_rx.try_recv().is_err());
+    ///
+    ///     drop(ok_rx);
+    ///     assert_eq!(Ok::<_, &str>(2).route(&ok_tx, &err_tx).await.unwrap_err().0, Ok(2));
+    /// });
+    /// ```
+    fn route(
+        self,
+        ok_tx: &Sender<A>,
+        err_tx: &Sender<E>,
+    ) -> impl Future<Output = Result<(), SendError<Result<A, E>>>> + Send
+    where
+        A: Send,
+        E: Send;
+}
+
+impl<A, E> AsyncResultExt<A, E> for Result<A, E> {
+    async fn or_try_concurrent<F, Fut, H>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce(E) -> Fut + Send,
+        Fut: Future<Output = Result<A, H>> + Send + 'static,
+        A: Send + 'static,
+        E: Send,
+        H: From<JoinError> + Send + 'static,
+    {
+        match self {
+            Ok(a) => Ok(a),
+            Err(e) => tokio::spawn(f(e)).await.unwrap_or_else(|j| Err(H::from(j))),
+        }
+    }
+
+    async fn route(
+        self,
+        ok_tx: &Sender<A>,
+        err_tx: &Sender<E>,
+    ) -> Result<(), SendError<Result<A, E>>>
+    where
+        A: Send,
+        E: Send,
+    {
+        match self {
+            Ok(a) => ok_tx.send(a).await.map_err(|SendError(a)| SendError(Ok(a))),
+            Err(e) => err_tx
+                .send(e)
+                .await
+                .map_err(|SendError(e)| SendError(Err(e))),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Extra utilities for handling iteration over fallible computations.
+//!
+//! This module extends capabilities offered by [`std::iter`].
+
+use crate::result::ResultAccumulator;
+use std::iter::Enumerate;
+
+/// Extension with a set of extra combinators for `Iterator`.
+pub trait IteratorExt: Iterator {
+    /// Applies the fallible `f` to every item, collecting every success or bailing out with the first error.
+    ///
+    /// ```
+    /// use lifterr::iter::IteratorExt;
+    ///
+    /// fn even(x: i32) -> Result<i32, String> {
+    ///     if x % 2 == 0 { Ok(x / 2) } else { Err(format!("{x} is odd")) }
+    /// }
+    ///
+    /// assert_eq!(vec![2, 4].into_iter().traverse(even), Ok(vec![1, 2]));
+    /// assert_eq!(vec![1, 2, 3].into_iter().traverse(even), Err("1 is odd".to_string()));
+    /// ```
+    fn traverse<B, E, F>(self, f: F) -> Result<Vec<B>, E>
+    where
+        F: FnMut(Self::Item) -> Result<B, E>,
+        Self: Sized,
+    {
+        self.map(f).collect()
+    }
+
+    /// Applies the fallible `f` to every item, collecting every success or accumulating every error.
+    ///
+    /// ```
+    /// use lifterr::iter::IteratorExt;
+    ///
+    /// fn even(x: i32) -> Result<i32, String> {
+    ///     if x % 2 == 0 { Ok(x / 2) } else { Err(format!("{x} is odd")) }
+    /// }
+    ///
+    /// assert_eq!(vec![2, 4].into_iter().traverse_all(even), Ok(vec![1, 2]));
+    /// assert_eq!(
+    ///     vec![1, 2, 3].into_iter().traverse_all(even),
+    ///     Err(vec!["1 is odd".to_string(), "3 is odd".to_string()])
+    /// );
+    /// ```
+    fn traverse_all<B, E, F>(self, f: F) -> Result<Vec<B>, Vec<E>>
+    where
+        F: FnMut(Self::Item) -> Result<B, E>,
+        Self: Sized,
+    {
+        let mut acc = ResultAccumulator::new();
+        for r in self.map(f) {
+            acc.push(r);
+        }
+        acc.finish()
+    }
+
+    /// Pairs every error with the position of its item in the original iterator, leaving successes untouched.
+    ///
+    /// ```
+    /// use lifterr::iter::IteratorExt;
+    ///
+    /// let items = vec![Ok(1), Err("e1"), Ok(3), Err("e2")];
+    ///
+    /// assert_eq!(
+    ///     items.into_iter().enumerate_errors().collect::<Vec<_>>(),
+    ///     vec![Ok(1), Err((1, "e1")), Ok(3), Err((3, "e2"))]
+    /// );
+    /// ```
+    fn enumerate_errors<A, E>(self) -> EnumerateErrors<Self>
+    where
+        Self: Iterator<Item = Result<A, E>> + Sized,
+    {
+        EnumerateErrors {
+            inner: self.enumerate(),
+        }
+    }
+
+    /// Collects every success or accumulates every error paired with the position of its item in the original iterator.
+    ///
+    /// ```
+    /// use lifterr::iter::IteratorExt;
+    ///
+    /// let all_ok = vec![Ok::<_, &str>(1), Ok(2)];
+    /// let partial = vec![Err("e0"), Ok(1), Err("e2"), Ok(3), Err("e4")];
+    ///
+    /// assert_eq!(all_ok.into_iter().try_collect_indexed_errors(), Ok(vec![1, 2]));
+    /// assert_eq!(
+    ///     partial.into_iter().try_collect_indexed_errors(),
+    ///     Err(vec![(0, "e0"), (2, "e2"), (4, "e4")])
+    /// );
+    /// ```
+    fn try_collect_indexed_errors<A, E>(self) -> Result<Vec<A>, Vec<(usize, E)>>
+    where
+        Self: Iterator<Item = Result<A, E>> + Sized,
+    {
+        self.enumerate_errors().traverse_all(|r| r)
+    }
+
+    /// Collects every success up to the first error, which is returned separately, ignoring any items after it.
+    ///
+    /// ```
+    /// use lifterr::iter::IteratorExt;
+    ///
+    /// let all_ok = vec![Ok::<_, &str>(1), Ok(2)];
+    /// let partial = vec![Ok(1), Ok(2), Err("e1"), Ok(4), Err("e2")];
+    ///
+    /// assert_eq!(all_ok.into_iter().take_oks_until_err(), (vec![1, 2], None));
+    /// assert_eq!(partial.into_iter().take_oks_until_err(), (vec![1, 2], Some("e1")));
+    /// ```
+    fn take_oks_until_err<A, E>(self) -> (Vec<A>, Option<E>)
+    where
+        Self: Iterator<Item = Result<A, E>> + Sized,
+    {
+        let mut oks = Vec::new();
+        for r in self {
+            match r {
+                Ok(a) => oks.push(a),
+                Err(e) => return (oks, Some(e)),
+            }
+        }
+        (oks, None)
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Iterator pairing errors with their positions, created by [`IteratorExt::enumerate_errors`].
+#[derive(Debug, Clone)]
+pub struct EnumerateErrors<I> {
+    inner: Enumerate<I>,
+}
+
+impl<A, E, I> Iterator for EnumerateErrors<I>
+where
+    I: Iterator<Item = Result<A, E>>,
+{
+    type Item = Result<A, (usize, E)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, r)| r.map_err(|e| (i, e)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
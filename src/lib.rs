@@ -1,8 +1,21 @@
 #![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
 
+#[cfg(feature = "either")]
+pub mod either;
+#[cfg(feature = "tokio")]
+pub mod future;
+pub mod iter;
 pub mod option;
+pub mod prelude;
 pub mod result;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod validated;
 
+pub use iter::IteratorExt;
 pub use option::OptionExt;
 pub use result::{IntoErr, IntoOk, Merge, ResultExt};
+
+#[cfg(feature = "derive")]
+pub use lifterr_derive::MergeTarget;
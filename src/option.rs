@@ -2,6 +2,11 @@
 //!
 //! This module extends capabilities offered by [`std::option`].
 
+use crate::result::sealed;
+use std::iter::Flatten;
+use std::ops::{ControlFlow, Deref};
+use std::option::IntoIter;
+
 /// Extension with a set of extra combinators for `Option<A>`.
 pub trait OptionExt<A> {
     /// Applies `f` yielding yet another option if `Some(x)` otherwise propagates `None`.
@@ -19,6 +24,113 @@ pub trait OptionExt<A> {
     where
         F: FnOnce() -> Option<B>;
 
+    /// Applies the partial `f` to a reference to `A` if `Some(a)`, pairing `a` with its result when present otherwise propagates `None`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn first(s: &&'static str) -> Option<char> { s.chars().next() }
+    ///
+    /// assert_eq!(Some("abc").and_zip(first), Some(("abc", 'a')));
+    /// assert_eq!(Some("").and_zip(first), None);
+    /// assert_eq!(None.and_zip(first), None);
+    /// ```
+    fn and_zip<B, F>(self, f: F) -> Option<(A, B)>
+    where
+        F: FnOnce(&A) -> Option<B>;
+
+    /// Computes yet another option with `f` only if `Some(a)`, pairing `a` with its result when present otherwise propagates `None`.
+    ///
+    /// Unlike [`Option::zip`], `f` isn't evaluated when `None`, sparing a costly computation that would be discarded.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(1).zip_with_lazy(|| Some("a")), Some((1, "a")));
+    /// assert_eq!(Some(1).zip_with_lazy(|| None::<&str>), None);
+    /// assert_eq!(None::<i32>.zip_with_lazy(|| -> Option<&str> { unreachable!() }), None);
+    /// ```
+    fn zip_with_lazy<B, F>(self, f: F) -> Option<(A, B)>
+    where
+        Self: Sized,
+        F: FnOnce() -> Option<B>,
+    {
+        self.and_zip(|_| f())
+    }
+
+    /// Combines two options with `f` as long as at least one is present, filling in the absent side with its default.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let add = |a: i32, b: i32| a + b;
+    ///
+    /// assert_eq!(Some(1).combine_or(Some(2), 0, 0, add), Some(3));
+    /// assert_eq!(Some(1).combine_or(None, 0, 10, add), Some(11));
+    /// assert_eq!(None.combine_or(Some(2), 10, 0, add), Some(12));
+    /// assert_eq!(None.combine_or(None, 10, 10, add), None);
+    /// ```
+    fn combine_or<B, C, F>(self, other: Option<B>, a_default: A, b_default: B, f: F) -> Option<C>
+    where
+        F: FnOnce(A, B) -> C;
+
+    /// Applies `f` to the single present value out of `self` and `other`, yielding `None` when both or neither are present.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let double = |a: i32| a * 2;
+    ///
+    /// assert_eq!(Some(1).xor_remap(None, double), Some(2));
+    /// assert_eq!(None.xor_remap(Some(2), double), Some(4));
+    /// assert_eq!(Some(1).xor_remap(Some(2), double), None);
+    /// assert_eq!(None.xor_remap(None, double), None);
+    /// ```
+    fn xor_remap<B, F>(self, other: Option<A>, f: F) -> Option<B>
+    where
+        F: FnOnce(A) -> B;
+
+    /// Pairs `a` with each of `others` if `Some(a)` otherwise propagates `None`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some('x').cartesian(vec![1, 2, 3]), Some(vec![('x', 1), ('x', 2), ('x', 3)]));
+    /// assert_eq!(Some('x').cartesian(Vec::<i32>::new()), Some(vec![]));
+    /// assert_eq!(None::<char>.cartesian(vec![1, 2, 3]), None);
+    /// ```
+    fn cartesian<B>(self, others: Vec<B>) -> Option<Vec<(A, B)>>
+    where
+        A: Clone;
+
+    /// Yields the smaller of two options, treating an absent side as no candidate so that `None` only results when both are absent.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(1).min_opt(Some(2)), Some(1));
+    /// assert_eq!(None.min_opt(Some(2)), Some(2));
+    /// assert_eq!(Some(1).min_opt(None), Some(1));
+    /// assert_eq!(None::<i32>.min_opt(None), None);
+    /// ```
+    fn min_opt(self, other: Option<A>) -> Option<A>
+    where
+        A: Ord;
+
+    /// Yields the greater of two options, treating an absent side as no candidate so that `None` only results when both are absent.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(1).max_opt(Some(2)), Some(2));
+    /// assert_eq!(None.max_opt(Some(2)), Some(2));
+    /// assert_eq!(Some(1).max_opt(None), Some(1));
+    /// assert_eq!(None::<i32>.max_opt(None), None);
+    /// ```
+    fn max_opt(self, other: Option<A>) -> Option<A>
+    where
+        A: Ord;
+
     /// Applies `f` yielding a value which is then wrapped into another option if `Some(x)` otherwise propagates `None`.
     ///
     /// ```
@@ -46,6 +158,22 @@ pub trait OptionExt<A> {
         self.remap(|| ())
     }
 
+    /// Applies `f` to `A` if `Some(a)` and `cond` holds, otherwise leaves the option untouched.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn some() -> Option<String> { Some("Bob".to_string()) }
+    ///
+    /// assert_eq!(some().map_when(true, |s| s.to_lowercase()), Some("bob".to_string()));
+    /// assert_eq!(some().map_when(false, |s| s.to_lowercase()), Some("Bob".to_string()));
+    /// assert_eq!(None.map_when(true, |s: String| s.to_lowercase()), None);
+    /// assert_eq!(None.map_when(false, |s: String| s.to_lowercase()), None);
+    /// ```
+    fn map_when<F>(self, cond: bool, f: F) -> Option<A>
+    where
+        F: FnOnce(A) -> A;
+
     /// Runs `f` with a reference to `A` when `Some(a)`.
     ///
     /// ```
@@ -56,6 +184,174 @@ pub trait OptionExt<A> {
     where
         F: FnOnce(&A);
 
+    /// Runs `f` with a mutable reference to `A` when `Some(a)`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(vec![1]).tap_mut(|v| v.push(2)), Some(vec![1, 2]));
+    /// assert_eq!(None::<Vec<i32>>.tap_mut(|v| v.push(2)), None);
+    /// ```
+    fn tap_mut<F>(self, f: F) -> Option<A>
+    where
+        F: FnOnce(&mut A);
+
+    /// Keeps `Some(a)` only when `pred` holds, otherwise runs `on_reject` with a reference to `a` before dropping it.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut rejected = Vec::new();
+    ///
+    /// assert_eq!(Some(2).filter_inspect(|a| a % 2 == 0, |a| rejected.push(*a)), Some(2));
+    /// assert_eq!(Some(3).filter_inspect(|a| a % 2 == 0, |a| rejected.push(*a)), None);
+    /// assert_eq!(None.filter_inspect(|a: &i32| a % 2 == 0, |a| rejected.push(*a)), None);
+    ///
+    /// assert_eq!(rejected, vec![3]);
+    /// ```
+    fn filter_inspect<F, G>(self, pred: F, on_reject: G) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool,
+        G: FnOnce(&A);
+
+    /// Consumes the option, running `f` with the owned `A` when `Some(a)`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut seen = Vec::new();
+    ///
+    /// Some("a".to_string()).on_some(|a| seen.push(a));
+    /// None::<String>.on_some(|a| seen.push(a));
+    ///
+    /// assert_eq!(seen, vec!["a".to_string()]);
+    /// ```
+    fn on_some<F>(self, f: F)
+    where
+        F: FnOnce(A);
+
+    /// Consumes the option, running `f` when `None`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut misses = 0;
+    ///
+    /// Some(1).on_none(|| misses += 1);
+    /// None::<i32>.on_none(|| misses += 1);
+    ///
+    /// assert_eq!(misses, 1);
+    /// ```
+    fn on_none<F>(self, f: F)
+    where
+        F: FnOnce();
+
+    /// Applies `f` to `A` if `Some(a)` otherwise yields `B::default()`, which is only constructed when needed.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static DEFAULTS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Score(i32);
+    ///
+    /// impl Default for Score {
+    ///     fn default() -> Self {
+    ///         DEFAULTS.fetch_add(1, Ordering::SeqCst);
+    ///         Score(0)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some(42).map_or_default(Score), Score(42));
+    /// assert_eq!(DEFAULTS.load(Ordering::SeqCst), 0);
+    ///
+    /// assert_eq!(None.map_or_default(Score), Score(0));
+    /// assert_eq!(DEFAULTS.load(Ordering::SeqCst), 1);
+    /// ```
+    fn map_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(A) -> B;
+
+    /// Folds the option as a sequence of at most one value, yielding `f(init, a)` if `Some(a)` otherwise `init` untouched.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(2).fold_some(40, |acc, a| acc + a), 42);
+    /// assert_eq!(None.fold_some(40, |acc, a: i32| acc + a), 40);
+    /// ```
+    fn fold_some<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, A) -> B;
+
+    /// Pushes `A` into `target` when `Some(a)`, returning whether it did so.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut target = Vec::new();
+    ///
+    /// assert!(Some(1).push_into(&mut target));
+    /// assert!(!None.push_into(&mut target));
+    /// assert!(Some(3).push_into(&mut target));
+    ///
+    /// assert_eq!(target, vec![1, 3]);
+    /// ```
+    fn push_into(self, target: &mut Vec<A>) -> bool;
+
+    /// Gets a reference to `A` when `Some(a)`, otherwise inserts the value computed by `f` and gets a reference to it.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut cache = None;
+    ///
+    /// assert_eq!(cache.get_or_compute(|| 42), &42);
+    /// assert_eq!(cache.get_or_compute(|| unreachable!()), &42);
+    /// assert_eq!(cache, Some(42));
+    /// ```
+    fn get_or_compute<F>(&mut self, f: F) -> &A
+    where
+        F: FnOnce() -> A;
+
+    /// Takes `A` out of the option, leaving `None` in its place, only when `Some(a)` and `pred` holds.
+    ///
+    /// Named so as not to collide with the inherent [`Option::take_if`], which offers a mutable reference to `pred`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let mut slot = Some(2);
+    /// assert_eq!(slot.take_when(|a| a % 2 == 1), None);
+    /// assert_eq!(slot, Some(2));
+    /// assert_eq!(slot.take_when(|a| a % 2 == 0), Some(2));
+    /// assert_eq!(slot, None);
+    /// assert_eq!(slot.take_when(|_| true), None);
+    /// assert_eq!(slot, None);
+    /// ```
+    fn take_when<F>(&mut self, pred: F) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool;
+
+    /// Gets a clone of `A` when `Some(a)`, otherwise the value computed by `f`, leaving the option untouched.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let cache = None;
+    ///
+    /// assert_eq!(Some(10).get_or_compute_cloned(|| unreachable!()), 10);
+    /// assert_eq!(cache.get_or_compute_cloned(|| 42), 42);
+    /// assert_eq!(cache, None);
+    /// ```
+    fn get_or_compute_cloned<F>(&self, f: F) -> A
+    where
+        A: Clone,
+        F: FnOnce() -> A;
+
     /// Recovers from an absent value with a total function.
     fn recover<F>(self, f: F) -> Option<A>
     where
@@ -79,6 +375,299 @@ pub trait OptionExt<A> {
     fn recover_with<F>(self, f: F) -> Option<A>
     where
         F: FnOnce() -> Option<A>;
+
+    /// Yields `Some(a)` untouched otherwise `Some` of the value computed by `f`, so that the option is always present.
+    ///
+    /// Unlike [`OptionExt::recover_with`], the fallback can't be absent.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn not_found() -> Option<i32> { None }
+    ///
+    /// assert_eq!(Some(10).or_default_with(|| unreachable!()), Some(10));
+    /// assert_eq!(not_found().or_default_with(|| 42), Some(42));
+    /// assert_eq!(not_found().recover_with(|| None), None);
+    /// ```
+    fn or_default_with<F>(self, f: F) -> Option<A>
+    where
+        F: FnOnce() -> A,
+        Self: Sized,
+    {
+        Some(self.or_compute(f))
+    }
+
+    /// Recovers from an absent value with a partial function, only attempting it when `guard` permits.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn not_found() -> Option<i32> { None }
+    /// fn fallback() -> Option<i32> { Some(42) }
+    ///
+    /// assert_eq!(Some(10).or_with_guard(|| unreachable!(), fallback), Some(10));
+    /// assert_eq!(not_found().or_with_guard(|| true, fallback), Some(42));
+    /// assert_eq!(not_found().or_with_guard(|| false, || unreachable!()), None);
+    /// ```
+    fn or_with_guard<F, G>(self, guard: G, f: F) -> Option<A>
+    where
+        F: FnOnce() -> Option<A>,
+        G: FnOnce() -> bool,
+        Self: Sized,
+    {
+        self.recover_with(|| if guard() { f() } else { None })
+    }
+
+    /// Extracts `A` if `Some(a)` otherwise computes it with `f`, being the terminal counterpart of [`OptionExt::recover`].
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn port<O: OptionExt<u16>>(configured: O) -> u16 {
+    ///     configured.or_compute(|| 8080)
+    /// }
+    ///
+    /// assert_eq!(port(Some(80)), 80);
+    /// assert_eq!(port(None), 8080);
+    /// ```
+    fn or_compute<F>(self, f: F) -> A
+    where
+        F: FnOnce() -> A;
+
+    /// Extracts `A` if `Some(a)` otherwise yields `A::default()`, being the terminal counterpart of recovering with a default.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn retries<O: OptionExt<u32>>(configured: O) -> u32 {
+    ///     configured.get_or_default()
+    /// }
+    ///
+    /// assert_eq!(retries(Some(3)), 3);
+    /// assert_eq!(retries(None), 0);
+    /// ```
+    fn get_or_default(self) -> A
+    where
+        A: Default,
+        Self: Sized,
+    {
+        self.or_compute(A::default)
+    }
+
+    /// Gets a clone of `A` if `Some(a)` otherwise yields `A::default()`, leaving the option untouched.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let name = Some("bob".to_string());
+    /// let missing = None::<String>;
+    ///
+    /// assert_eq!(name.get_or_default_ref(), "bob".to_string());
+    /// assert_eq!(missing.get_or_default_ref(), String::new());
+    /// assert_eq!(name, Some("bob".to_string()));
+    /// ```
+    fn get_or_default_ref(&self) -> A
+    where
+        A: Clone + Default,
+    {
+        self.get_or_compute_cloned(A::default)
+    }
+
+    /// Applies `f` yielding yet another option if `Some(a)` otherwise applies `g` to recover from `None`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn half(x: i32) -> Option<i32> { (x % 2 == 0).then(|| x / 2) }
+    /// fn fallback() -> Option<i32> { Some(0) }
+    ///
+    /// assert_eq!(Some(4).and_then_or(half, fallback), Some(2));
+    /// assert_eq!(Some(3).and_then_or(half, fallback), None);
+    /// assert_eq!(None.and_then_or(half, fallback), Some(0));
+    /// ```
+    fn and_then_or<B, F, G>(self, f: F, g: G) -> Option<B>
+    where
+        F: FnOnce(A) -> Option<B>,
+        G: FnOnce() -> Option<B>;
+
+    /// Splits a pair into its halves, applying `f` to the first and `g` to the second if `Some((x, y))` otherwise yields `(None, None)`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn some() -> Option<(i32, &'static str)> { Some((1, "42")) }
+    /// fn none() -> Option<(i32, &'static str)> { None }
+    ///
+    /// assert_eq!(some().unzip_with(|x| x + 1, str::len), (Some(2), Some(2)));
+    /// assert_eq!(none().unzip_with(|x| x + 1, str::len), (None, None));
+    /// ```
+    fn unzip_with<X, Y, B, C, F, G>(self, f: F, g: G) -> (Option<B>, Option<C>)
+    where
+        A: Into<(X, Y)>,
+        F: FnOnce(X) -> B,
+        G: FnOnce(Y) -> C;
+
+    /// Pairs `Some(a)` with `Some(b)` into `Ok((a, b))` otherwise fails with `err`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(1).zip_result(Some("42"), "missing"), Ok((1, "42")));
+    /// assert_eq!(Some(1).zip_result(None::<&str>, "missing"), Err("missing"));
+    /// assert_eq!(None::<i32>.zip_result(Some("42"), "missing"), Err("missing"));
+    /// ```
+    fn zip_result<B, E>(self, other: Option<B>, err: E) -> Result<(A, B), E>
+    where
+        Self: Sized,
+    {
+        self.zip_result_with(other, || err)
+    }
+
+    /// Pairs `Some(a)` with `Some(b)` into `Ok((a, b))` otherwise fails with the error computed by `f`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(1).zip_result_with(Some("42"), || -> &str { unreachable!() }), Ok((1, "42")));
+    /// assert_eq!(Some(1).zip_result_with(None::<&str>, || "missing"), Err("missing"));
+    /// ```
+    fn zip_result_with<B, E, F>(self, other: Option<B>, f: F) -> Result<(A, B), E>
+    where
+        F: FnOnce() -> E;
+
+    /// Requires the value to be present, yielding `Ok(a)` if `Some(a)` otherwise failing with `err`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some("bob").require_or("name is required"), Ok("bob"));
+    /// assert_eq!(None::<&str>.require_or("name is required"), Err("name is required"));
+    /// ```
+    fn require_or<E>(self, err: E) -> Result<A, E>
+    where
+        Self: Sized,
+    {
+        self.require(|| err)
+    }
+
+    /// Requires the value to be present, yielding `Ok(a)` if `Some(a)` otherwise failing with the error computed by `f`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some("bob").require(|| -> String { unreachable!() }), Ok("bob"));
+    /// assert_eq!(None::<&str>.require(|| format!("{} is required", "name")), Err("name is required".to_string()));
+    /// ```
+    fn require<E, F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce() -> E;
+
+    /// Borrows the option as a result, yielding `Ok(&a)` if `Some(a)` otherwise failing with `err`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let name = Some("bob".to_string());
+    /// let missing = None::<String>;
+    ///
+    /// assert_eq!(name.as_result("missing"), Ok(&"bob".to_string()));
+    /// assert_eq!(missing.as_result("missing"), Err("missing"));
+    ///
+    /// assert_eq!(name, Some("bob".to_string()));
+    /// ```
+    fn as_result<E>(&self, err: E) -> Result<&A, E>;
+
+    /// Transposes an `Option<Result<B, E>>` into a `Result<Option<B>, H>`, applying `f` to the error if `Some(Err(e))`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(Ok::<_, &str>(1)).transpose_or(str::len), Ok(Some(1)));
+    /// assert_eq!(Some(Err::<i32, _>("e")).transpose_or(str::len), Err(1));
+    /// assert_eq!(None::<Result<i32, &str>>.transpose_or(str::len), Ok(None));
+    /// ```
+    fn transpose_or<H, F>(self, f: F) -> Result<Option<A::Ok>, H>
+    where
+        A: sealed::Fallible,
+        F: FnOnce(A::Err) -> H;
+
+    /// Turns `Some(a)` into `ControlFlow::Continue(a)` or `None` into `ControlFlow::Break(())`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// assert_eq!(Some(1).into_control_flow(), ControlFlow::Continue(1));
+    /// assert_eq!(None::<i32>.into_control_flow(), ControlFlow::Break(()));
+    ///
+    /// let sum = |xs: &[Option<i32>]| xs.iter().try_fold(0, |acc, x| x.map(|n| acc + n).into_control_flow());
+    ///
+    /// assert_eq!(sum(&[Some(1), Some(2)]), ControlFlow::Continue(3));
+    /// assert_eq!(sum(&[Some(1), None, Some(2)]), ControlFlow::Break(()));
+    /// ```
+    fn into_control_flow(self) -> ControlFlow<(), A>
+    where
+        Self: Sized,
+    {
+        self.break_value(())
+    }
+
+    /// Turns `Some(a)` into `ControlFlow::Continue(a)` or `None` into `ControlFlow::Break(value)`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    /// use std::ops::ControlFlow;
+    ///
+    /// assert_eq!(Some(1).break_value("missing"), ControlFlow::Continue(1));
+    /// assert_eq!(None::<i32>.break_value("missing"), ControlFlow::Break("missing"));
+    /// ```
+    fn break_value<B>(self, value: B) -> ControlFlow<B, A>;
+
+    /// Iterates over the items of `A` if `Some(a)` otherwise over nothing.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// assert_eq!(Some(vec![1, 2]).flatten_iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(None::<Vec<i32>>.flatten_iter().count(), 0);
+    /// ```
+    fn flatten_iter(self) -> Flatten<IntoIter<A>>
+    where
+        A: IntoIterator;
+
+    /// Clones the borrowed value out of `Some(&a)` otherwise propagates `None`.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// let value = "value".to_string();
+    ///
+    /// assert_eq!(Some(&value).cloned_some(), Some("value".to_string()));
+    /// assert_eq!(None::<&String>.cloned_some(), None);
+    /// ```
+    fn cloned_some(self) -> Option<A::Target>
+    where
+        A: Deref,
+        A::Target: Clone;
+
+    /// Passes the whole option to `f`, handing over to custom logic whenever no combinator fits while keeping a method chain.
+    ///
+    /// ```
+    /// use lifterr::option::OptionExt;
+    ///
+    /// fn status(user: Option<&str>) -> u16 {
+    ///     match user {
+    ///         Some(_) => 200,
+    ///         None => 404,
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Some("bob").pipe(status), 200);
+    /// assert_eq!(None.pipe(status), 404);
+    /// ```
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Option<A>) -> B;
 }
 
 impl<A> OptionExt<A> for Option<A> {
@@ -89,6 +678,68 @@ impl<A> OptionExt<A> for Option<A> {
         self.and_then(|_| f())
     }
 
+    fn and_zip<B, F>(self, f: F) -> Option<(A, B)>
+    where
+        F: FnOnce(&A) -> Option<B>,
+    {
+        self.and_then(|a| f(&a).map(|b| (a, b)))
+    }
+
+    fn combine_or<B, C, F>(self, other: Option<B>, a_default: A, b_default: B, f: F) -> Option<C>
+    where
+        F: FnOnce(A, B) -> C,
+    {
+        match (self, other) {
+            (None, None) => None,
+            (a, b) => Some(f(a.unwrap_or(a_default), b.unwrap_or(b_default))),
+        }
+    }
+
+    fn xor_remap<B, F>(self, other: Option<A>, f: F) -> Option<B>
+    where
+        F: FnOnce(A) -> B,
+    {
+        self.xor(other).map(f)
+    }
+
+    fn cartesian<B>(self, others: Vec<B>) -> Option<Vec<(A, B)>>
+    where
+        A: Clone,
+    {
+        self.map(|a| others.into_iter().map(|b| (a.clone(), b)).collect())
+    }
+
+    fn min_opt(self, other: Option<A>) -> Option<A>
+    where
+        A: Ord,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn max_opt(self, other: Option<A>) -> Option<A>
+    where
+        A: Ord,
+    {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn map_when<F>(self, cond: bool, f: F) -> Option<A>
+    where
+        F: FnOnce(A) -> A,
+    {
+        if cond {
+            self.map(f)
+        } else {
+            self
+        }
+    }
+
     fn inspect<F>(self, f: F) -> Option<A>
     where
         F: FnOnce(&A),
@@ -99,10 +750,277 @@ impl<A> OptionExt<A> for Option<A> {
         })
     }
 
+    fn tap_mut<F>(self, f: F) -> Option<A>
+    where
+        F: FnOnce(&mut A),
+    {
+        self.map(|mut a| {
+            f(&mut a);
+            a
+        })
+    }
+
+    fn filter_inspect<F, G>(self, pred: F, on_reject: G) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool,
+        G: FnOnce(&A),
+    {
+        self.filter(|a| {
+            let keep = pred(a);
+            if !keep {
+                on_reject(a);
+            }
+            keep
+        })
+    }
+
+    fn on_some<F>(self, f: F)
+    where
+        F: FnOnce(A),
+    {
+        if let Some(a) = self {
+            f(a)
+        }
+    }
+
+    fn on_none<F>(self, f: F)
+    where
+        F: FnOnce(),
+    {
+        if self.is_none() {
+            f()
+        }
+    }
+
+    fn map_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(A) -> B,
+    {
+        self.map_or_else(B::default, f)
+    }
+
+    fn fold_some<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, A) -> B,
+    {
+        match self {
+            Some(a) => f(init, a),
+            None => init,
+        }
+    }
+
+    fn push_into(self, target: &mut Vec<A>) -> bool {
+        match self {
+            Some(a) => {
+                target.push(a);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_or_compute<F>(&mut self, f: F) -> &A
+    where
+        F: FnOnce() -> A,
+    {
+        self.get_or_insert_with(f)
+    }
+
+    fn take_when<F>(&mut self, pred: F) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool,
+    {
+        match self {
+            Some(a) if pred(a) => self.take(),
+            _ => None,
+        }
+    }
+
+    fn get_or_compute_cloned<F>(&self, f: F) -> A
+    where
+        A: Clone,
+        F: FnOnce() -> A,
+    {
+        self.as_ref().map_or_else(f, A::clone)
+    }
+
     fn recover_with<F>(self, f: F) -> Option<A>
     where
         F: FnOnce() -> Option<A>,
     {
         self.map_or_else(f, A::into)
     }
+
+    fn or_compute<F>(self, f: F) -> A
+    where
+        F: FnOnce() -> A,
+    {
+        self.unwrap_or_else(f)
+    }
+
+    fn and_then_or<B, F, G>(self, f: F, g: G) -> Option<B>
+    where
+        F: FnOnce(A) -> Option<B>,
+        G: FnOnce() -> Option<B>,
+    {
+        self.map_or_else(g, f)
+    }
+
+    fn unzip_with<X, Y, B, C, F, G>(self, f: F, g: G) -> (Option<B>, Option<C>)
+    where
+        A: Into<(X, Y)>,
+        F: FnOnce(X) -> B,
+        G: FnOnce(Y) -> C,
+    {
+        match self {
+            Some(a) => {
+                let (x, y) = a.into();
+                (Some(f(x)), Some(g(y)))
+            }
+            None => (None, None),
+        }
+    }
+
+    fn zip_result_with<B, E, F>(self, other: Option<B>, f: F) -> Result<(A, B), E>
+    where
+        F: FnOnce() -> E,
+    {
+        self.zip(other).ok_or_else(f)
+    }
+
+    fn require<E, F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce() -> E,
+    {
+        self.ok_or_else(f)
+    }
+
+    fn as_result<E>(&self, err: E) -> Result<&A, E> {
+        self.as_ref().ok_or(err)
+    }
+
+    fn transpose_or<H, F>(self, f: F) -> Result<Option<A::Ok>, H>
+    where
+        A: sealed::Fallible,
+        F: FnOnce(A::Err) -> H,
+    {
+        self.map_or(Ok(None), |a| a.into_result().map(Some).map_err(f))
+    }
+
+    fn flatten_iter(self) -> Flatten<IntoIter<A>>
+    where
+        A: IntoIterator,
+    {
+        self.into_iter().flatten()
+    }
+
+    fn cloned_some(self) -> Option<A::Target>
+    where
+        A: Deref,
+        A::Target: Clone,
+    {
+        self.map(|a| a.deref().clone())
+    }
+
+    fn break_value<B>(self, value: B) -> ControlFlow<B, A> {
+        match self {
+            Some(a) => ControlFlow::Continue(a),
+            None => ControlFlow::Break(value),
+        }
+    }
+
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Option<A>) -> B,
+    {
+        f(self)
+    }
+}
+
+/// Tries each optional source in order, yielding the first present value.
+///
+/// ```
+/// use lifterr::option::first_some_of;
+///
+/// type Source = Box<dyn FnOnce() -> Option<i32>>;
+///
+/// let sources: Vec<Source> = vec![Box::new(|| None), Box::new(|| Some(42)), Box::new(|| unreachable!())];
+/// assert_eq!(first_some_of(sources), Some(42));
+///
+/// let sources: Vec<Source> = vec![Box::new(|| None), Box::new(|| None)];
+/// assert_eq!(first_some_of(sources), None);
+/// ```
+pub fn first_some_of<A>(sources: Vec<Box<dyn FnOnce() -> Option<A>>>) -> Option<A> {
+    sources.into_iter().find_map(|source| source())
+}
+
+/// Accumulator of optional values pushed one at a time, giving up for good as soon as an absent one is pushed.
+///
+/// ```
+/// use lifterr::option::OptionAccumulator;
+///
+/// let mut all_present = OptionAccumulator::new();
+/// all_present.push(Some(1));
+/// all_present.push(Some(2));
+/// assert_eq!(all_present.finish(), Some(vec![1, 2]));
+///
+/// let mut poisoned = OptionAccumulator::new();
+/// poisoned.push(Some(1));
+/// poisoned.push(None);
+/// poisoned.push(Some(3));
+/// assert_eq!(poisoned.finish(), None);
+///
+/// assert_eq!(OptionAccumulator::<i32>::new().finish(), Some(vec![]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct OptionAccumulator<A> {
+    values: Option<Vec<A>>,
+}
+
+impl<A> OptionAccumulator<A> {
+    /// Creates an accumulator without any values.
+    pub fn new() -> Self {
+        OptionAccumulator {
+            values: Some(Vec::new()),
+        }
+    }
+
+    /// Keeps `a` if `Some(a)` otherwise discards every value accumulated so far as well as those pushed afterwards.
+    pub fn push(&mut self, item: Option<A>) {
+        match (&mut self.values, item) {
+            (Some(values), Some(a)) => values.push(a),
+            _ => self.values = None,
+        }
+    }
+
+    /// Yields every value accumulated when all of them were present, otherwise `None`.
+    pub fn finish(self) -> Option<Vec<A>> {
+        self.values
+    }
+}
+
+impl<A> Default for OptionAccumulator<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Extension with a set of sequencing combinators for `Vec<Option<A>>`.
+pub trait SequenceExt<A> {
+    /// Turns a vector of options into an option of a vector, yielding `None` as soon as an absent value is found.
+    ///
+    /// ```
+    /// use lifterr::option::SequenceExt;
+    ///
+    /// assert_eq!(vec![Some(1), Some(2)].sequence(), Some(vec![1, 2]));
+    /// assert_eq!(vec![Some(1), None, Some(3)].sequence(), None);
+    /// ```
+    fn sequence(self) -> Option<Vec<A>>;
+}
+
+impl<A> SequenceExt<A> for Vec<Option<A>> {
+    fn sequence(self) -> Option<Vec<A>> {
+        self.into_iter().collect()
+    }
 }
@@ -0,0 +1,42 @@
+//! Convenient re-exports of every extension trait and of the validation builder.
+//!
+//! A single glob import brings the whole fluent API into scope.
+//!
+//! ```
+//! use lifterr::prelude::*;
+//!
+//! fn parse(s: &str) -> Result<i32, &'static str> {
+//!     s.parse::<i32>().ok().tap_mut(|n| *n *= 2).ok_or("nan").remap(|| 42)
+//! }
+//!
+//! fn merged(s: &str) -> &'static str {
+//!     parse(s).then(|| "ok".into_ok()).merge()
+//! }
+//!
+//! assert_eq!(parse("1"), Ok(42));
+//! assert_eq!(merged("x"), "nan");
+//! assert_eq!(vec![Some(1), Some(2)].sequence(), Some(vec![1, 2]));
+//! assert_eq!(vec![Ok::<_, &str>(1), Err("e")].sequence(), Err("e"));
+//! assert_eq!(ValidatedBuilder::new().field(parse("1")).build(|n| n), Ok(42));
+//! ```
+
+pub use crate::iter::IteratorExt;
+pub use crate::option::OptionExt;
+pub use crate::result::{IntoErr, IntoOk, Merge, ResultExt};
+pub use crate::validated::ValidatedBuilder;
+
+#[cfg(feature = "either")]
+pub use crate::either::{EitherExt, LiftEither};
+
+#[cfg(feature = "tokio")]
+pub use crate::future::AsyncResultExt;
+
+#[cfg(feature = "futures")]
+pub use crate::stream::TryStreamExt;
+
+#[cfg(feature = "derive")]
+pub use crate::MergeTarget;
+
+// Both modules offer a `SequenceExt`, so they're only brought into scope anonymously.
+pub use crate::option::SequenceExt as _;
+pub use crate::result::SequenceExt as _;
@@ -2,6 +2,21 @@
 //!
 //! This module extends capabilities offered by [`std::result`].
 
+use crate::iter::IteratorExt;
+#[cfg(feature = "either")]
+use ::either::Either;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+#[cfg(feature = "future")]
+use std::future::{self, Ready};
+use std::iter::{self, Flatten};
+#[cfg(feature = "either")]
+use std::iter::{Map, Once};
+use std::ops::{ControlFlow, Deref};
+use std::panic::Location;
+use std::result::IntoIter;
+use std::time::{Duration, Instant};
+
 /// Extension with a set of extra combinators for `Result<A, E>`.
 pub trait ResultExt<A, E> {
     /// Applies `f` yielding yet another result if `Ok(x)` otherwise propagates `Err`.
@@ -19,6 +34,69 @@ pub trait ResultExt<A, E> {
     where
         F: FnOnce() -> Result<B, E>;
 
+    /// Applies the fallible `f` to a reference to `A` if `Ok(a)`, pairing `a` with its success otherwise propagates `Err`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn len(s: &&'static str) -> Result<usize, &'static str> { if s.is_empty() { Err("empty") } else { Ok(s.len()) } }
+    ///
+    /// assert_eq!(Ok("abc").and_zip(len), Ok(("abc", 3)));
+    /// assert_eq!(Ok("").and_zip(len), Err("empty"));
+    /// assert_eq!(Err("e").and_zip(len), Err("e"));
+    /// ```
+    fn and_zip<B, F>(self, f: F) -> Result<(A, B), E>
+    where
+        F: FnOnce(&A) -> Result<B, E>;
+
+    /// Computes yet another result with `f` only if `Ok(a)`, pairing `a` with its success otherwise propagates the first `Err`.
+    ///
+    /// Unlike zipping two eagerly computed results, `f` isn't evaluated when `Err`, sparing a costly computation that would be discarded.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e1") }
+    ///
+    /// assert_eq!(ok().zip_with_lazy(|| Ok("a")), Ok((1, "a")));
+    /// assert_eq!(ok().zip_with_lazy(|| Err::<&str, _>("e2")), Err("e2"));
+    /// assert_eq!(err().zip_with_lazy(|| Err::<&str, _>("e2")), Err("e1"));
+    /// assert_eq!(err().zip_with_lazy(|| -> Result<&str, _> { unreachable!() }), Err("e1"));
+    /// ```
+    fn zip_with_lazy<B, F>(self, f: F) -> Result<(A, B), E>
+    where
+        Self: Sized,
+        F: FnOnce() -> Result<B, E>,
+    {
+        self.and_zip(|_| f())
+    }
+
+    /// Combines two results, merging the values with `merge_ok` when both are `Ok` or the errors with `merge_err` when both are `Err`, otherwise propagating the single error.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok(a: i32) -> Result<i32, String> { Ok(a) }
+    /// fn err(e: &str) -> Result<i32, String> { Err(e.to_string()) }
+    ///
+    /// let join = |mut e1: String, e2: String| { e1.push_str(", "); e1.push_str(&e2); e1 };
+    ///
+    /// assert_eq!(ok(1).combine(ok(2), join, |a, b| a + b), Ok(3));
+    /// assert_eq!(ok(1).combine(err("e2"), join, |a, b| a + b), Err("e2".to_string()));
+    /// assert_eq!(err("e1").combine(ok(2), join, |a, b| a + b), Err("e1".to_string()));
+    /// assert_eq!(err("e1").combine(err("e2"), join, |a, b| a + b), Err("e1, e2".to_string()));
+    /// ```
+    fn combine<B, C, FE, FV>(
+        self,
+        other: Result<B, E>,
+        merge_err: FE,
+        merge_ok: FV,
+    ) -> Result<C, E>
+    where
+        FE: FnOnce(E, E) -> E,
+        FV: FnOnce(A, B) -> C;
+
     /// Applies `f` yielding a value which is then wrapped into another result if `Ok(x)` otherwise propagates `Err`.
     ///
     /// ```
@@ -89,6 +167,100 @@ pub trait ResultExt<A, E> {
         self.remap_err(|| ())
     }
 
+    /// Wraps the error into a higher-level one with `f` if `Err(e)` otherwise propagates `Ok`.
+    ///
+    /// Unlike [`ResultExt::remap_err`], `f` receives the original error so that it can be embedded as a source.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct ConfigError { source: &'static str }
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Opaque;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().wrap_err(|source| ConfigError { source }), Ok(1));
+    /// assert_eq!(err().wrap_err(|source| ConfigError { source }), Err(ConfigError { source: "e" }));
+    /// assert_eq!(err().wrap_err(|_| Opaque), Err(Opaque));
+    /// ```
+    fn wrap_err<W, F>(self, f: F) -> Result<A, W>
+    where
+        F: FnOnce(E) -> W;
+
+    /// Wraps the error into a one-element vector if `Err(e)` otherwise propagates `Ok`.
+    ///
+    /// This lets a fail-fast result compose with those accumulating every error into a `Vec<E>`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().errs(), Ok(1));
+    /// assert_eq!(err().errs(), Err(vec!["e"]));
+    /// ```
+    fn errs(self) -> Result<A, Vec<E>>;
+
+    /// Destructures the error into a pair of parts and rebuilds it with `f` if `Err(e)` otherwise propagates `Ok`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct HttpError { code: u16, message: &'static str }
+    ///
+    /// fn ok() -> Result<i32, (u16, &'static str)> { Ok(1) }
+    /// fn err() -> Result<i32, (u16, &'static str)> { Err((404, "not found")) }
+    ///
+    /// assert_eq!(ok().map_err_parts(|code, message| HttpError { code, message }), Ok(1));
+    /// assert_eq!(
+    ///     err().map_err_parts(|code, message| HttpError { code, message }),
+    ///     Err(HttpError { code: 404, message: "not found" })
+    /// );
+    /// ```
+    fn map_err_parts<X, Y, H, F>(self, f: F) -> Result<A, H>
+    where
+        E: Into<(X, Y)>,
+        F: FnOnce(X, Y) -> H;
+
+    /// Maps the error with the fallible `f` if `Err(e)` otherwise propagates `Ok`.
+    ///
+    /// Either way the outcome of `f` is an error: the mapped one if `Ok(h)` or the one raised while mapping if `Err(h)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn status(e: &str) -> Result<u16, u16> { e.parse().map_err(|_| 500) }
+    ///
+    /// assert_eq!(Ok::<_, &str>(1).try_map_err(status), Ok(1));
+    /// assert_eq!(Err::<i32, _>("404").try_map_err(status), Err(404));
+    /// assert_eq!(Err::<i32, _>("not found").try_map_err(status), Err(500));
+    /// ```
+    fn try_map_err<H, F>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce(E) -> Result<H, H>;
+
+    /// Applies `f` to `A` if `Ok(a)` and `cond` holds, otherwise leaves the result untouched.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<String, &'static str> { Ok("Bob".to_string()) }
+    /// fn err() -> Result<String, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().map_when(true, |s| s.to_lowercase()), Ok("bob".to_string()));
+    /// assert_eq!(ok().map_when(false, |s| s.to_lowercase()), Ok("Bob".to_string()));
+    /// assert_eq!(err().map_when(true, |s| s.to_lowercase()), Err("e"));
+    /// ```
+    fn map_when<F>(self, cond: bool, f: F) -> Result<A, E>
+    where
+        F: FnOnce(A) -> A;
+
     /// Runs `f` with a reference to `A` when `Ok(a)`.
     ///
     /// ```
@@ -109,156 +281,1964 @@ pub trait ResultExt<A, E> {
     where
         F: FnOnce(&E);
 
-    /// Swaps `Ok(o)` into `Err(o)` or `Err(e)` into `Ok(e)`.
+    /// Consumes the result, running `f` with the owned `A` when `Ok(a)`.
     ///
     /// ```
     /// use lifterr::result::ResultExt;
+    /// use std::sync::mpsc;
     ///
-    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
-    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    /// let (tx, rx) = mpsc::channel();
     ///
-    /// assert_eq!(ok().swap(), Err(1));
-    /// assert_eq!(err().swap(), Ok("e"));
+    /// Ok::<_, String>("ok".to_string()).on_ok(|a| tx.send(a).unwrap());
+    /// Err::<String, _>("e".to_string()).on_ok(|a| tx.send(a).unwrap());
+    ///
+    /// drop(tx);
+    /// assert_eq!(rx.iter().collect::<Vec<_>>(), vec!["ok".to_string()]);
     /// ```
-    fn swap(self) -> Result<E, A>;
-
-    /// Recovers from an error of type `E` with a non-fallible function.
-    fn recover<F>(self, f: F) -> Result<A, E>
+    fn on_ok<F>(self, f: F)
     where
-        F: FnOnce(E) -> A,
-        Self: Sized,
-    {
-        self.recover_with(|e| f(e).into_ok())
-    }
+        F: FnOnce(A);
 
-    /// Recovers from an error of type `E` with a fallible function, possibly remapping to a different error of type `H`.
+    /// Consumes the result, running `f` with the owned `E` when `Err(e)`.
     ///
     /// ```
     /// use lifterr::result::ResultExt;
+    /// use std::sync::mpsc;
     ///
-    /// fn err_not_fine() -> Result<i32, &'static str> { Err("bad error") }
-    /// fn err_fine() -> Result<i32, &'static str> { Err("42") }
+    /// let (tx, rx) = mpsc::channel();
     ///
-    /// assert_eq!(err_not_fine().recover_with(|e| if e == "42" { Ok(42) } else { Err("not fine") }), Err("not fine"));
-    /// assert_eq!(err_fine().recover_with(|e| if e == "42" { Ok(42) } else { Err("not fine") }), Ok(42));
+    /// Ok::<_, String>("ok".to_string()).on_err(|e| tx.send(e).unwrap());
+    /// Err::<String, _>("e".to_string()).on_err(|e| tx.send(e).unwrap());
+    ///
+    /// drop(tx);
+    /// assert_eq!(rx.iter().collect::<Vec<_>>(), vec!["e".to_string()]);
     /// ```
-    fn recover_with<F, H>(self, f: F) -> Result<A, H>
+    fn on_err<F>(self, f: F)
     where
-        F: FnOnce(E) -> Result<A, H>;
-}
+        F: FnOnce(E);
 
-impl<A, E> ResultExt<A, E> for Result<A, E> {
-    fn then<F, B>(self, f: F) -> Result<B, E>
+    /// Runs `f` with a mutable reference to `A` when `Ok(a)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, Vec<i32>>(vec![1]).map_in_place(|v| v.push(2)), Ok(vec![1, 2]));
+    /// assert_eq!(Err::<Vec<i32>, _>(vec![1]).map_in_place(|v| v.push(2)), Err(vec![1]));
+    /// ```
+    fn map_in_place<F>(self, f: F) -> Result<A, E>
     where
-        F: FnOnce() -> Result<B, E>,
-    {
-        self.and_then(|_| f())
-    }
+        F: FnOnce(&mut A);
 
-    fn then_err<F, H>(self, f: F) -> Result<A, H>
+    /// Runs `f` with a mutable reference to `E` when `Err(e)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// assert_eq!(Ok::<_, Vec<i32>>(vec![1]).map_err_in_place(|v| v.push(2)), Ok(vec![1]));
+    /// assert_eq!(Err::<Vec<i32>, _>(vec![1]).map_err_in_place(|v| v.push(2)), Err(vec![1, 2]));
+    /// ```
+    fn map_err_in_place<F>(self, f: F) -> Result<A, E>
     where
-        F: FnOnce() -> Result<A, H>,
-    {
-        self.or_else(|_| f())
-    }
+        F: FnOnce(&mut E);
 
-    fn inspect<F>(self, f: F) -> Result<A, E>
+    /// Checks whether it's `Ok(a)` and `a` satisfies `pred`.
+    ///
+    /// Named so as not to collide with the inherent [`Result::is_ok_and`].
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert!(ok().matches_ok(|a| *a == 1));
+    /// assert!(!ok().matches_ok(|a| *a == 2));
+    /// assert!(!err().matches_ok(|_| true));
+    /// ```
+    fn matches_ok<F>(self, pred: F) -> bool
     where
-        F: FnOnce(&A),
-    {
-        self.map(|a| {
-            f(&a);
-            a
-        })
-    }
+        F: FnOnce(&A) -> bool;
 
-    fn inspect_err<F>(self, f: F) -> Result<A, E>
+    /// Checks whether it's `Err(e)` and `e` satisfies `pred`.
+    ///
+    /// Named so as not to collide with the inherent [`Result::is_err_and`].
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert!(err().matches_err(|e| *e == "e"));
+    /// assert!(!err().matches_err(|e| e.is_empty()));
+    /// assert!(!ok().matches_err(|_| true));
+    /// ```
+    fn matches_err<F>(self, pred: F) -> bool
     where
-        F: FnOnce(&E),
-    {
-        self.map_err(|e| {
-            f(&e);
-            e
-        })
-    }
+        F: FnOnce(&E) -> bool;
 
-    fn swap(self) -> Result<E, A> {
-        match self {
-            Ok(o) => Err(o),
-            Err(e) => Ok(e),
-        }
-    }
+    /// Consumes the result, yielding what `f` decides about the owned `A` if `Ok(a)` otherwise `false`.
+    ///
+    /// Unlike [`ResultExt::matches_ok`], `f` takes ownership of `a`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<Vec<i32>, &'static str> { Ok(vec![1]) }
+    /// fn err() -> Result<Vec<i32>, &'static str> { Err("e") }
+    ///
+    /// assert!(ok().ok_then(|a| a.into_iter().all(|n| n > 0)));
+    /// assert!(!ok().ok_then(|a| a.is_empty()));
+    /// assert!(!err().ok_then(|_| true));
+    /// ```
+    fn ok_then<F>(self, f: F) -> bool
+    where
+        F: FnOnce(A) -> bool;
 
-    fn recover_with<F, H>(self, f: F) -> Result<A, H>
+    /// Consumes the result, yielding what `f` decides about the owned `E` if `Err(e)` otherwise `false`.
+    ///
+    /// Unlike [`ResultExt::matches_err`], `f` takes ownership of `e`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, String> { Ok(1) }
+    /// fn err() -> Result<i32, String> { Err("e".to_string()) }
+    ///
+    /// assert!(err().err_then(|e| e == "e"));
+    /// assert!(!err().err_then(|e| e.is_empty()));
+    /// assert!(!ok().err_then(|_| true));
+    /// ```
+    fn err_then<F>(self, f: F) -> bool
     where
-        F: FnOnce(E) -> Result<A, H>,
-    {
-        self.map_or_else(f, A::into_ok)
-    }
-}
+        F: FnOnce(E) -> bool;
 
-/// Ability to merge branches of a `Result<A, E>` when `A` and `E` are compatible (e.g. when they unify under an `Into<T>` conversion).
-pub trait Merge<T> {
-    /// Merges both branches of a result, giving preference to the `Ok` branch when needed.
+    /// Turns `Ok(a)` into `Some(a)` only when `a` satisfies `pred`, discarding errors and rejected values as `None`.
     ///
     /// ```
-    /// use lifterr::result::Merge;
+    /// use lifterr::result::ResultExt;
     ///
-    /// fn merge_ok() -> i32 {
-    ///     Ok::<_, i32>(42).merge()
-    /// }
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
     ///
-    /// fn merge_err() -> i32 {
-    ///     Err::<i32, _>(42).merge()
+    /// assert_eq!(ok().ok_if(|a| *a > 0), Some(1));
+    /// assert_eq!(ok().ok_if(|a| *a < 0), None);
+    /// assert_eq!(err().ok_if(|_| true), None);
+    /// ```
+    fn ok_if<F>(self, pred: F) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool;
+
+    /// Applies `f` to `A` if `Ok(a)` otherwise discards the error and yields `B::default()`, which is only constructed when needed.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static DEFAULTS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Score(i32);
+    ///
+    /// impl Default for Score {
+    ///     fn default() -> Self {
+    ///         DEFAULTS.fetch_add(1, Ordering::SeqCst);
+    ///         Score(0)
+    ///     }
     /// }
     ///
-    /// assert_eq!(merge_ok(), 42);
-    /// assert_eq!(merge_err(), 42);
+    /// assert_eq!(Ok::<_, &str>(42).map_or_default(Score), Score(42));
+    /// assert_eq!(DEFAULTS.load(Ordering::SeqCst), 0);
+    ///
+    /// assert_eq!(Err("e").map_or_default(Score), Score(0));
+    /// assert_eq!(DEFAULTS.load(Ordering::SeqCst), 1);
     /// ```
-    fn merge(self) -> T;
-}
-
-impl<A, E, T> Merge<T> for Result<A, E>
-where
-    T: From<A> + From<E>,
-{
-    fn merge(self) -> T {
-        self.map_or_else(T::from, T::from)
-    }
-}
+    fn map_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(A) -> B;
 
-/// Lifter of values into successful results.
-pub trait IntoOk<O> {
-    /// Lifts a value of type `O` into a `Result<O, E>` by wrapping it into an `Ok`.
+    /// Applies `f` to `E` if `Err(e)` otherwise discards the value and yields `B::default()`, which is only constructed when needed.
     ///
     /// ```
-    /// use lifterr::result::IntoOk;
+    /// use lifterr::result::ResultExt;
     ///
-    /// assert_eq!(42.into_ok::<&'static str>(), Ok(42));
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().map_err_or_default(str::to_string), String::new());
+    /// assert_eq!(err().map_err_or_default(str::to_string), "e".to_string());
     /// ```
-    fn into_ok<E>(self) -> Result<O, E>;
-}
-
-impl<O> IntoOk<O> for O {
-    fn into_ok<E>(self) -> Result<O, E> {
-        Ok(self)
-    }
-}
+    fn map_err_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(E) -> B;
 
-/// Lifter of values into failed results.
-pub trait IntoErr<E> {
-    /// Lifts a value of type `E` into a `Result<O, E>` by wrapping it into an `Err`.
+    /// Folds the result as a sequence of at most one value, yielding `f(init, a)` if `Ok(a)` otherwise `init` untouched.
     ///
     /// ```
-    /// use lifterr::result::IntoErr;
+    /// use lifterr::result::ResultExt;
     ///
-    /// assert_eq!("e".into_err::<i32>(), Err("e"));
+    /// fn ok() -> Result<i32, &'static str> { Ok(2) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().fold_ok(40, |acc, a| acc + a), 42);
+    /// assert_eq!(err().fold_ok(40, |acc, a| acc + a), 40);
+    ///
+    /// let items = vec![Ok(1), Err("e"), Ok(2)];
+    /// assert_eq!(items.into_iter().fold(0, |acc, r| r.fold_ok(acc, |acc, a| acc + a)), 3);
     /// ```
-    fn into_err<O>(self) -> Result<O, E>;
-}
+    fn fold_ok<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, A) -> B;
 
-impl<E> IntoErr<E> for E {
-    fn into_err<O>(self) -> Result<O, E> {
-        Err(self)
+    /// Folds the result as a sequence of at most one error, yielding `f(init, e)` if `Err(e)` otherwise `init` untouched.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let items = vec![Ok(1), Err("e1"), Ok(2), Err("e2")];
+    ///
+    /// let (sum, errors) = items.into_iter().fold((0, Vec::new()), |(sum, errors), r: Result<i32, &str>| {
+    ///     (r.fold_ok(sum, |sum, a| sum + a), r.fold_err(errors, |mut errors, e| { errors.push(e); errors }))
+    /// });
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(errors, vec!["e1", "e2"]);
+    /// ```
+    fn fold_err<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, E) -> B;
+
+    /// Clones `A` into `slot` when `Ok(a)`, leaving `slot` untouched otherwise.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// let mut slot = None;
+    ///
+    /// assert_eq!(err().tee(&mut slot), Err("e"));
+    /// assert_eq!(slot, None);
+    ///
+    /// assert_eq!(ok().tee(&mut slot), Ok(1));
+    /// assert_eq!(slot, Some(1));
+    /// ```
+    fn tee(self, slot: &mut Option<A>) -> Result<A, E>
+    where
+        A: Clone,
+        Self: Sized,
+    {
+        self.inspect(|a| *slot = Some(a.clone()))
+    }
+
+    /// Increments `counter` when `Err(e)`, passing the result through.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let mut failures = 0;
+    ///
+    /// for r in vec![Ok(1), Err("e1"), Ok(3), Err("e2")] {
+    ///     let _ = r.tally(&mut failures);
+    /// }
+    ///
+    /// assert_eq!(failures, 2);
+    /// assert_eq!(Err::<i32, _>("e").tally(&mut failures), Err("e"));
+    /// assert_eq!(failures, 3);
+    /// ```
+    fn tally(self, counter: &mut usize) -> Result<A, E>
+    where
+        Self: Sized,
+    {
+        self.inspect_err(|_| *counter += 1)
+    }
+
+    /// Increments `counter` when `Ok(a)`, passing the result through.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let mut successes = 0;
+    ///
+    /// for r in vec![Ok(1), Err("e1"), Ok(3), Err("e2")] {
+    ///     let _ = r.tally_ok(&mut successes);
+    /// }
+    ///
+    /// assert_eq!(successes, 2);
+    /// assert_eq!(Ok::<_, &str>(1).tally_ok(&mut successes), Ok(1));
+    /// assert_eq!(successes, 3);
+    /// ```
+    fn tally_ok(self, counter: &mut usize) -> Result<A, E>
+    where
+        Self: Sized,
+    {
+        self.inspect(|_| *counter += 1)
+    }
+
+    /// Runs `f` with the time elapsed since `start` regardless of the branch, passing the result through.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::time::Instant;
+    ///
+    /// let mut timings = Vec::new();
+    ///
+    /// let start = Instant::now();
+    /// assert_eq!("42".parse::<i32>().tap_elapsed(start, |d| timings.push(d)), Ok(42));
+    ///
+    /// let start = Instant::now();
+    /// assert!("x".parse::<i32>().tap_elapsed(start, |d| timings.push(d)).is_err());
+    ///
+    /// assert_eq!(timings.len(), 2);
+    /// ```
+    fn tap_elapsed<F>(self, start: Instant, f: F) -> Result<A, E>
+    where
+        F: FnOnce(Duration);
+
+    /// Prints the whole result to stderr via `Debug` regardless of the branch, passing the result through.
+    ///
+    /// Like `dbg!`, it's meant for quick debugging, though it prints nothing in builds without `debug_assertions`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// assert_eq!("42".parse::<i32>().map_err(|_| "nan").tap_dbg(), Ok(42));
+    /// assert_eq!("x".parse::<i32>().map_err(|_| "nan").tap_dbg(), Err("nan"));
+    /// ```
+    fn tap_dbg(self) -> Result<A, E>
+    where
+        A: Debug,
+        E: Debug;
+
+    /// Pushes `A` into `oks` when `Ok(a)` or `E` into `errs` when `Err(e)`, returning whether it was `Ok`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let (mut oks, mut errs) = (Vec::new(), Vec::new());
+    ///
+    /// assert!(Ok::<_, &str>(1).sort_into(&mut oks, &mut errs));
+    /// assert!(!Err::<i32, _>("e").sort_into(&mut oks, &mut errs));
+    /// assert!(Ok::<_, &str>(3).sort_into(&mut oks, &mut errs));
+    ///
+    /// assert_eq!(oks, vec![1, 3]);
+    /// assert_eq!(errs, vec!["e"]);
+    /// ```
+    fn sort_into(self, oks: &mut Vec<A>, errs: &mut Vec<E>) -> bool;
+
+    /// Swaps `Ok(o)` into `Err(o)` or `Err(e)` into `Ok(e)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().swap(), Err(1));
+    /// assert_eq!(err().swap(), Ok("e"));
+    /// ```
+    fn swap(self) -> Result<E, A>;
+
+    /// Borrows the result, applying `f` to `&A` if `Ok(a)` or `g` to `&E` if `Err(e)` while keeping the original usable.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let ok: Result<String, String> = Ok("abc".to_string());
+    /// let err: Result<String, String> = Err("e".to_string());
+    ///
+    /// assert_eq!(ok.dual_ref(String::len, |e| e.to_uppercase()), Ok(3));
+    /// assert_eq!(err.dual_ref(String::len, |e| e.to_uppercase()), Err("E".to_string()));
+    ///
+    /// assert_eq!(ok, Ok("abc".to_string()));
+    /// assert_eq!(err, Err("e".to_string()));
+    /// ```
+    fn dual_ref<B, H, F, G>(&self, f: F, g: G) -> Result<B, H>
+    where
+        F: FnOnce(&A) -> B,
+        G: FnOnce(&E) -> H;
+
+    /// Applies `f` to whichever branch holds a value when `E` unifies with `A`, keeping the original variant.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, i32> { Ok(1) }
+    /// fn err() -> Result<i32, i32> { Err(2) }
+    ///
+    /// assert_eq!(ok().map_same(|n| n * 2), Ok(2));
+    /// assert_eq!(err().map_same(|n| n * 2), Err(4));
+    /// ```
+    fn map_same<B, F>(self, f: F) -> Result<B, B>
+    where
+        E: Into<A>,
+        F: FnOnce(A) -> B;
+
+    /// Borrows `A` as `Some(&a)` if `Ok(a)` otherwise yields `None`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().as_ok_ref(), Some(&1));
+    /// assert_eq!(err().as_ok_ref(), None);
+    /// ```
+    fn as_ok_ref(&self) -> Option<&A>;
+
+    /// Borrows `E` as `Some(&e)` if `Err(e)` otherwise yields `None`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().as_err_ref(), None);
+    /// assert_eq!(err().as_err_ref(), Some(&"e"));
+    /// ```
+    fn as_err_ref(&self) -> Option<&E>;
+
+    /// Borrows the dereferenced `A` as `Some(&a)` if `Ok(a)` otherwise yields `None`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<String, &'static str> { Ok("value".to_string()) }
+    /// fn err() -> Result<String, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().as_deref_ok(), Some("value"));
+    /// assert_eq!(err().as_deref_ok(), None);
+    /// ```
+    fn as_deref_ok(&self) -> Option<&A::Target>
+    where
+        A: Deref;
+
+    /// Clones the borrowed value out of `Ok(&a)`, leaving `Err` untouched.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let value = "value".to_string();
+    ///
+    /// assert_eq!(Ok::<_, &str>(&value).cloned_ok(), Ok("value".to_string()));
+    /// assert_eq!(Err::<&String, _>("e").cloned_ok(), Err("e"));
+    /// ```
+    fn cloned_ok(self) -> Result<A::Target, E>
+    where
+        A: Deref,
+        A::Target: Clone;
+
+    /// Clones the borrowed error out of `Err(&e)`, leaving `Ok` untouched.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let error = "e".to_string();
+    ///
+    /// assert_eq!(Ok::<_, &String>(1).cloned_err(), Ok(1));
+    /// assert_eq!(Err::<i32, _>(&error).cloned_err(), Err("e".to_string()));
+    /// ```
+    fn cloned_err(self) -> Result<A, E::Target>
+    where
+        E: Deref,
+        E::Target: Clone;
+
+    /// Applies the partial `f` to a reference to `A` if `Ok(a)` otherwise yields `None`, leaving the result untouched.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let ok: Result<String, &str> = Ok("abc".to_string());
+    /// let empty: Result<String, &str> = Ok(String::new());
+    /// let err: Result<String, &str> = Err("e");
+    ///
+    /// assert_eq!(ok.ok_ref_and_then(|s| s.chars().next()), Some('a'));
+    /// assert_eq!(empty.ok_ref_and_then(|s| s.chars().next()), None);
+    /// assert_eq!(err.ok_ref_and_then(|s| s.chars().next()), None);
+    /// assert_eq!(ok, Ok("abc".to_string()));
+    /// ```
+    fn ok_ref_and_then<B, F>(&self, f: F) -> Option<B>
+    where
+        F: FnOnce(&A) -> Option<B>;
+
+    /// Merges both branches of a borrowed result, similarly to [`Merge::merge`] but without consuming it.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::borrow::Cow;
+    ///
+    /// let ok: Result<String, String> = Ok("value".to_string());
+    /// let err: Result<String, String> = Err("error".to_string());
+    ///
+    /// assert_eq!(ok.merge_ref::<Cow<str>>(), "value");
+    /// assert_eq!(err.merge_ref::<Cow<str>>(), "error");
+    /// assert_eq!(ok, Ok("value".to_string()));
+    /// ```
+    fn merge_ref<'a, T>(&'a self) -> T
+    where
+        A: 'a,
+        E: 'a,
+        T: From<&'a A> + From<&'a E>;
+
+    /// Turns `Ok(a)` into `ControlFlow::Continue(a)` or `Err(e)` into `ControlFlow::Break(e)`.
+    ///
+    /// ```
+    /// use lifterr::result::{from_control_flow, ResultExt};
+    /// use std::ops::ControlFlow;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().into_control_flow(), ControlFlow::Continue(1));
+    /// assert_eq!(err().into_control_flow(), ControlFlow::Break("e"));
+    ///
+    /// let sum = |xs: &[&str]| {
+    ///     from_control_flow(xs.iter().try_fold(0, |acc, x| x.parse::<i32>().map(|n| acc + n).into_control_flow()))
+    /// };
+    ///
+    /// assert_eq!(sum(&["1", "2"]), Ok(3));
+    /// assert!(sum(&["1", "x", "2"]).is_err());
+    /// ```
+    fn into_control_flow(self) -> ControlFlow<E, A>;
+
+    /// Iterates over the items of `A` if `Ok(a)` otherwise over nothing, discarding the error.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<Vec<i32>, &'static str> { Ok(vec![1, 2]) }
+    /// fn err() -> Result<Vec<i32>, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().ok_iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(err().ok_iter().count(), 0);
+    /// ```
+    fn ok_iter(self) -> Flatten<IntoIter<A>>
+    where
+        A: IntoIterator;
+
+    /// Iterates over the items of `A` as `Left` values if `Ok(a)` otherwise over the single error as a `Right` value.
+    ///
+    /// This method is only available with the `either` feature enabled.
+    ///
+    /// ```
+    /// use either::Either;
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<Vec<i32>, &'static str> { Ok(vec![1, 2]) }
+    /// fn err() -> Result<Vec<i32>, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().ok_iter_or_err().collect::<Vec<_>>(), vec![Either::Left(1), Either::Left(2)]);
+    /// assert_eq!(err().ok_iter_or_err().collect::<Vec<_>>(), vec![Either::Right("e")]);
+    /// ```
+    #[cfg(feature = "either")]
+    #[allow(clippy::type_complexity)]
+    fn ok_iter_or_err(
+        self,
+    ) -> Either<Map<A::IntoIter, fn(A::Item) -> Either<A::Item, E>>, Once<Either<A::Item, E>>>
+    where
+        A: IntoIterator;
+
+    /// Wraps the result into an immediately ready future.
+    ///
+    /// This method is only available with the `future` feature enabled.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll};
+    /// # use std::ptr;
+    /// # use std::task::{RawWaker, RawWakerVTable, Waker};
+    /// #
+    /// # fn noop_waker() -> Waker {
+    /// #     fn clone(_: *const ()) -> RawWaker { RawWaker::new(ptr::null(), &VTABLE) }
+    /// #     fn noop(_: *const ()) {}
+    /// #     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    /// #     unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) }
+    /// # }
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    ///
+    /// assert_eq!(Pin::new(&mut ok().ready()).poll(&mut cx), Poll::Ready(Ok(1)));
+    /// assert_eq!(Pin::new(&mut err().ready()).poll(&mut cx), Poll::Ready(Err("e")));
+    /// ```
+    #[cfg(feature = "future")]
+    fn ready(self) -> Ready<Result<A, E>>;
+
+    /// Attaches a context computed by `f` to the error when `Err(e)`, yielding `Err((e, c))` otherwise propagates `Ok`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().annotate_err(|| -> &str { unreachable!() }), Ok(1));
+    /// assert_eq!(err().annotate_err(|| "while validating"), Err(("e", "while validating")));
+    /// ```
+    fn annotate_err<F, C>(self, f: F) -> Result<A, (E, C)>
+    where
+        F: FnOnce() -> C;
+
+    /// Erases the type of the error by boxing it into a trait object.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::error::Error;
+    ///
+    /// let errors: Vec<Box<dyn Error + Send + Sync>> = vec![
+    ///     "x".parse::<i32>().box_err().unwrap_err(),
+    ///     "y".parse::<f64>().box_err().unwrap_err(),
+    /// ];
+    ///
+    /// assert_eq!(errors[0].to_string(), "invalid digit found in string");
+    /// assert_eq!(errors[1].to_string(), "invalid float literal");
+    /// assert_eq!("1".parse::<i32>().box_err().ok(), Some(1));
+    /// ```
+    fn box_err(self) -> Result<A, Box<dyn Error + Send + Sync>>
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Attempts to downcast the boxed error into the concrete `T` when `Err(e)`, handing back the box when it holds
+    /// a different type.
+    ///
+    /// Both `Box<dyn Error>` and `Box<dyn Error + Send + Sync>` are supported, the latter as returned by
+    /// [`ResultExt::box_err`].
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::error::Error;
+    /// use std::num::{ParseFloatError, ParseIntError};
+    ///
+    /// fn parse(s: &str) -> Result<i32, Box<dyn Error>> { Ok(s.parse()?) }
+    ///
+    /// assert_eq!(parse("1").downcast_err::<ParseIntError>().unwrap(), Ok(1));
+    /// assert_eq!(parse("x").downcast_err::<ParseIntError>().unwrap(), Err("x".parse::<i32>().unwrap_err()));
+    /// assert_eq!(
+    ///     parse("x").downcast_err::<ParseFloatError>().unwrap_err().to_string(),
+    ///     "invalid digit found in string"
+    /// );
+    ///
+    /// let boxed = || "x".parse::<i32>().box_err();
+    /// let mismatched: Box<dyn Error + Send + Sync> = boxed().downcast_err::<ParseFloatError>().unwrap_err();
+    ///
+    /// assert_eq!(boxed().downcast_err::<ParseIntError>().unwrap(), Err("x".parse::<i32>().unwrap_err()));
+    /// assert_eq!(mismatched.to_string(), "invalid digit found in string");
+    /// ```
+    fn downcast_err<T>(self) -> Result<Result<A, T>, E>
+    where
+        E: sealed::BoxedError,
+        T: Error + 'static;
+
+    /// Converts the error into an [`anyhow::Error`] when `Err(e)`, so that a chain can end in an anyhow-based application.
+    ///
+    /// This method is only available with the `anyhow` feature enabled.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// assert_eq!("x".parse::<i32>().into_anyhow().unwrap_err().to_string(), "invalid digit found in string");
+    /// assert_eq!("1".parse::<i32>().into_anyhow().ok(), Some(1));
+    /// ```
+    #[cfg(feature = "anyhow")]
+    fn into_anyhow(self) -> anyhow::Result<A>
+    where
+        E: Error + Send + Sync + 'static;
+
+    /// Converts the error into an [`anyhow::Error`] with `ctx` attached on top of it when `Err(e)`.
+    ///
+    /// Named so as not to collide with `anyhow::Context::context` when both traits are in scope. This method is only
+    /// available with the `anyhow` feature enabled.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// let e = "x".parse::<i32>().context_anyhow("parsing port").unwrap_err();
+    ///
+    /// assert_eq!(e.to_string(), "parsing port");
+    /// assert_eq!(
+    ///     e.chain().map(ToString::to_string).collect::<Vec<_>>(),
+    ///     vec!["parsing port".to_string(), "invalid digit found in string".to_string()]
+    /// );
+    /// assert_eq!("1".parse::<i32>().context_anyhow("parsing port").ok(), Some(1));
+    /// ```
+    #[cfg(feature = "anyhow")]
+    fn context_anyhow<C>(self, ctx: C) -> anyhow::Result<A>
+    where
+        C: Display + Send + Sync + 'static,
+        E: Error + Send + Sync + 'static;
+
+    /// Captures the location where it's called alongside the error when `Err(e)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// let (r, line) = (err().err_with_location(), line!());
+    /// let e = r.unwrap_err();
+    ///
+    /// assert_eq!(e.error(), &"e");
+    /// assert_eq!(e.location().file(), file!());
+    /// assert_eq!(e.location().line(), line);
+    /// ```
+    #[track_caller]
+    fn err_with_location(self) -> Result<A, LocatedError<E>>;
+
+    /// Starts a trail of error layers when `Err(e)`, yielding `Err(vec![ctx, e])` with both rendered via `Display`.
+    ///
+    /// Further layers are added to an existing trail with [`ResultExt::prepend_context`].
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("bad digit") }
+    ///
+    /// assert_eq!(ok().push_context("parsing"), Ok(1));
+    /// assert_eq!(err().push_context("parsing"), Err(vec!["parsing".to_string(), "bad digit".to_string()]));
+    /// ```
+    fn push_context<C>(self, ctx: C) -> Result<A, Vec<String>>
+    where
+        C: Display,
+        E: Display;
+
+    /// Prepends `ctx` rendered via `Display` to a trail of error layers when `Err(e)`, keeping the outermost layer first.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn err() -> Result<i32, &'static str> { Err("bad digit") }
+    ///
+    /// assert_eq!(
+    ///     err().push_context("parsing").prepend_context("loading config"),
+    ///     Err(vec!["loading config".to_string(), "parsing".to_string(), "bad digit".to_string()])
+    /// );
+    /// ```
+    fn prepend_context<C>(self, ctx: C) -> Result<A, Vec<String>>
+    where
+        C: Display,
+        E: Into<Vec<String>>;
+
+    /// Renders the error and every one of its sources via `Display` when `Err(e)`, keeping the outermost layer first.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::error::Error;
+    /// use std::fmt;
+    ///
+    /// #[derive(Debug)]
+    /// struct Io;
+    ///
+    /// impl fmt::Display for Io {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "disk unavailable") }
+    /// }
+    ///
+    /// impl Error for Io {}
+    ///
+    /// #[derive(Debug)]
+    /// struct Config(Io);
+    ///
+    /// impl fmt::Display for Config {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "cannot load config") }
+    /// }
+    ///
+    /// impl Error for Config {
+    ///     fn source(&self) -> Option<&(dyn Error + 'static)> { Some(&self.0) }
+    /// }
+    ///
+    /// assert_eq!(Ok::<_, Config>(1).err_chain(), Ok(1));
+    /// assert_eq!(
+    ///     Err::<i32, _>(Config(Io)).err_chain(),
+    ///     Err(vec!["cannot load config".to_string(), "disk unavailable".to_string()])
+    /// );
+    /// ```
+    fn err_chain(self) -> Result<A, Vec<String>>
+    where
+        E: Error;
+
+    /// Recovers from an error of type `E` with a non-fallible function.
+    fn recover<F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(E) -> A,
+        Self: Sized,
+    {
+        self.recover_with(|e| f(e).into_ok())
+    }
+
+    /// Recovers from an error of type `E` by converting it into `A`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<String, &'static str> { Ok("value".to_string()) }
+    /// fn err() -> Result<String, &'static str> { Err("fallback") }
+    ///
+    /// assert_eq!(ok().recover_into(), Ok("value".to_string()));
+    /// assert_eq!(err().recover_into(), Ok("fallback".to_string()));
+    /// ```
+    fn recover_into(self) -> Result<A, E>
+    where
+        E: Into<A>,
+        Self: Sized,
+    {
+        self.recover(Into::into)
+    }
+
+    /// Recovers from an error of type `E` with a fallible function, possibly remapping to a different error of type `H`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn err_not_fine() -> Result<i32, &'static str> { Err("bad error") }
+    /// fn err_fine() -> Result<i32, &'static str> { Err("42") }
+    ///
+    /// assert_eq!(err_not_fine().recover_with(|e| if e == "42" { Ok(42) } else { Err("not fine") }), Err("not fine"));
+    /// assert_eq!(err_fine().recover_with(|e| if e == "42" { Ok(42) } else { Err("not fine") }), Ok(42));
+    /// ```
+    fn recover_with<F, H>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce(E) -> Result<A, H>;
+
+    /// Recovers from an error of type `E` with a fallible function borrowing it, keeping the original error whenever
+    /// the recovery fails.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn fallback(e: &&'static str) -> Result<i32, ()> { if *e == "missing" { Ok(0) } else { Err(()) } }
+    ///
+    /// assert_eq!(Ok(1).recover_with_original(fallback), Ok(1));
+    /// assert_eq!(Err("missing").recover_with_original(fallback), Ok(0));
+    /// assert_eq!(Err("corrupt").recover_with_original(fallback), Err("corrupt"));
+    /// ```
+    fn recover_with_original<F, H>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&E) -> Result<A, H>;
+
+    /// Applies `f` yielding yet another result if `Ok(a)` otherwise applies `g` to recover from `Err(e)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn half(x: i32) -> Result<i32, &'static str> { if x % 2 == 0 { Ok(x / 2) } else { Err("odd") } }
+    /// fn fallback(e: &'static str) -> Result<i32, &'static str> { if e == "missing" { Ok(0) } else { Err(e) } }
+    ///
+    /// assert_eq!(Ok(4).and_then_or(half, fallback), Ok(2));
+    /// assert_eq!(Ok(3).and_then_or(half, fallback), Err("odd"));
+    /// assert_eq!(Err("missing").and_then_or(half, fallback), Ok(0));
+    /// assert_eq!(Err("broken").and_then_or(half, fallback), Err("broken"));
+    /// ```
+    fn and_then_or<B, F, G>(self, f: F, g: G) -> Result<B, E>
+    where
+        F: FnOnce(A) -> Result<B, E>,
+        G: FnOnce(E) -> Result<B, E>;
+
+    /// Recovers from an error of type `E` with a non-fallible function, but only when `classify` deems it recoverable.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn err(e: &'static str) -> Result<i32, &'static str> { Err(e) }
+    ///
+    /// assert_eq!(err("payload").recover_classify(|e| *e == "payload", |_| 0), Ok(0));
+    /// assert_eq!(err("size").recover_classify(|e| *e == "payload", |_| 0), Err("size"));
+    /// ```
+    fn recover_classify<F, G>(self, classify: F, recover: G) -> Result<A, E>
+    where
+        F: FnOnce(&E) -> bool,
+        G: FnOnce(E) -> A,
+        Self: Sized,
+    {
+        self.recover_with(|e| if classify(&e) { Ok(recover(e)) } else { Err(e) })
+    }
+
+    /// Recovers from an error of type `E` with the fallible `recover`, then applies `then` to whichever `A` results,
+    /// so that both recovered and successful values flow through the same mapping.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn parse(s: &str) -> Result<i32, String> { s.parse().map_err(|_| s.to_string()) }
+    /// fn defaults(e: String) -> Result<i32, String> { if e.is_empty() { Ok(0) } else { Err(e) } }
+    ///
+    /// assert_eq!(parse("41").recover_map(defaults, |n| n + 1), Ok(42));
+    /// assert_eq!(parse("").recover_map(defaults, |n| n + 1), Ok(1));
+    /// assert_eq!(parse("x").recover_map(defaults, |n| n + 1), Err("x".to_string()));
+    /// ```
+    fn recover_map<B, F, G>(self, recover: G, then: F) -> Result<B, E>
+    where
+        F: FnOnce(A) -> B,
+        G: FnOnce(E) -> Result<A, E>,
+        Self: Sized,
+    {
+        self.recover_with(recover).map(then)
+    }
+
+    /// Extracts `A` when `Ok(a)` otherwise panics at the caller's location with `msg` followed by the error.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::panic;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// fn ok() -> Result<i32, &'static str> { Ok(1) }
+    /// fn err() -> Result<i32, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(ok().expect_ok("boom"), 1);
+    ///
+    /// let panicked_at = Arc::new(Mutex::new(None));
+    /// let hook_panicked_at = Arc::clone(&panicked_at);
+    /// panic::set_hook(Box::new(move |info| {
+    ///     *hook_panicked_at.lock().unwrap() = info.location().map(|l| (l.file().to_string(), l.line()));
+    /// }));
+    ///
+    /// let (r, line) = (panic::catch_unwind(|| err().expect_ok("boom")), line!());
+    /// let _ = panic::take_hook();
+    ///
+    /// assert!(r.is_err());
+    /// assert_eq!(*panicked_at.lock().unwrap(), Some((file!().to_string(), line)));
+    /// ```
+    #[track_caller]
+    fn expect_ok(self, msg: &str) -> A
+    where
+        E: Debug;
+
+    /// Extracts `E` when `Err(e)` otherwise panics at the caller's location with `msg` followed by the value.
+    ///
+    /// Unlike the inherent [`Result::expect_err`], it's reachable from generic code bounded by `ResultExt`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    /// use std::panic;
+    ///
+    /// fn failure<R: ResultExt<i32, &'static str>>(r: R) -> &'static str {
+    ///     r.expect_err("expected a failure")
+    /// }
+    ///
+    /// fn success<R: ResultExt<i32, &'static str>>(r: R) -> i32 {
+    ///     r.expect_ok("expected a success")
+    /// }
+    ///
+    /// assert_eq!(failure(Err("e")), "e");
+    /// assert_eq!(success(Ok(1)), 1);
+    ///
+    /// fn message<T: std::fmt::Debug>(r: std::thread::Result<T>) -> String {
+    ///     *r.unwrap_err().downcast::<String>().unwrap()
+    /// }
+    ///
+    /// assert_eq!(message(panic::catch_unwind(|| failure(Ok(1)))), "expected a failure: 1");
+    /// assert_eq!(message(panic::catch_unwind(|| success(Err("e")))), "expected a success: \"e\"");
+    /// ```
+    #[track_caller]
+    fn expect_err(self, msg: &str) -> E
+    where
+        A: Debug;
+
+    /// Extracts `E` when `Err(e)` otherwise turns the unexpected `Ok(a)` into an error with `f`.
+    ///
+    /// This is the dual of [`Result::unwrap_or_else`], for contexts where failure is the expected outcome.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn ok() -> Result<i32, String> { Ok(1) }
+    /// fn err() -> Result<i32, String> { Err("e".to_string()) }
+    ///
+    /// assert_eq!(err().expect_err_or(|a| format!("unexpected {a}")), "e");
+    /// assert_eq!(ok().expect_err_or(|a| format!("unexpected {a}")), "unexpected 1");
+    /// ```
+    fn expect_err_or<F>(self, f: F) -> E
+    where
+        F: FnOnce(A) -> E;
+
+    /// Flattens a nested `Result<Result<B, G>, E>` into a `Result<B, H>`, converting both the outer and inner errors into `H`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum AppError { Io(&'static str), Parse(&'static str) }
+    ///
+    /// impl From<&'static str> for AppError {
+    ///     fn from(e: &'static str) -> Self { AppError::Io(e) }
+    /// }
+    ///
+    /// impl From<char> for AppError {
+    ///     fn from(_: char) -> Self { AppError::Parse("bad char") }
+    /// }
+    ///
+    /// fn nested(outer: bool, inner: bool) -> Result<Result<i32, char>, &'static str> {
+    ///     if !outer { Err("io") } else if !inner { Ok(Err('x')) } else { Ok(Ok(42)) }
+    /// }
+    ///
+    /// assert_eq!(nested(true, true).flatten_err_into::<AppError>(), Ok(42));
+    /// assert_eq!(nested(true, false).flatten_err_into::<AppError>(), Err(AppError::Parse("bad char")));
+    /// assert_eq!(nested(false, true).flatten_err_into::<AppError>(), Err(AppError::Io("io")));
+    /// ```
+    fn flatten_err_into<H>(self) -> Result<A::Ok, H>
+    where
+        A: sealed::Fallible,
+        H: From<E> + From<A::Err>;
+
+    /// Flattens a nested `Result<Result<B, E>, E>` into a `Result<B, E>`, adjusting the outer error with `on_outer` and the inner one with `on_inner`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn nested(outer: bool, inner: bool) -> Result<Result<i32, String>, String> {
+    ///     if !outer { Err("io".to_string()) } else if !inner { Ok(Err("parse".to_string())) } else { Ok(Ok(42)) }
+    /// }
+    ///
+    /// let outer = |e| format!("outer: {e}");
+    /// let inner = |e| format!("inner: {e}");
+    ///
+    /// assert_eq!(nested(true, true).flatten_with(outer, inner), Ok(42));
+    /// assert_eq!(nested(false, true).flatten_with(outer, inner), Err("outer: io".to_string()));
+    /// assert_eq!(nested(true, false).flatten_with(outer, inner), Err("inner: parse".to_string()));
+    /// ```
+    fn flatten_with<F, G>(self, on_outer: F, on_inner: G) -> Result<A::Ok, E>
+    where
+        A: sealed::Fallible<Err = E>,
+        F: FnOnce(E) -> E,
+        G: FnOnce(E) -> E;
+
+    /// Collapses a `Result<Option<B>, E>` into a `Result<B, E>`, falling back to `B::default()` when `Ok(None)`.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn found() -> Result<Option<Vec<i32>>, &'static str> { Ok(Some(vec![1, 2])) }
+    /// fn not_found() -> Result<Option<Vec<i32>>, &'static str> { Ok(None) }
+    /// fn err() -> Result<Option<Vec<i32>>, &'static str> { Err("e") }
+    ///
+    /// assert_eq!(found().or_default_inner(), Ok(vec![1, 2]));
+    /// assert_eq!(not_found().or_default_inner(), Ok(vec![]));
+    /// assert_eq!(err().or_default_inner(), Err("e"));
+    /// assert_eq!(not_found().or_default_inner().map(|v| v.len()), Ok(0));
+    /// ```
+    fn or_default_inner(self) -> Result<A::Inner, E>
+    where
+        A: sealed::Optional,
+        A::Inner: Default;
+
+    /// Flattens a `Result<Vec<Result<B, E>>, E>` into a `Result<Vec<B>, E>`, failing with the outer error or else the first inner one.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn outer_err() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Err("outer") }
+    /// fn inner_err() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Ok(vec![Ok(1), Err("e1"), Err("e2")]) }
+    /// fn all_ok() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Ok(vec![Ok(1), Ok(2)]) }
+    ///
+    /// assert_eq!(outer_err().flatten_vec(), Err("outer"));
+    /// assert_eq!(inner_err().flatten_vec(), Err("e1"));
+    /// assert_eq!(all_ok().flatten_vec(), Ok(vec![1, 2]));
+    /// ```
+    fn flatten_vec<B>(self) -> Result<Vec<B>, E>
+    where
+        A: IntoIterator<Item = Result<B, E>>;
+
+    /// Flattens a `Result<Vec<Result<B, E>>, E>` into a `Result<Vec<B>, Vec<E>>`, failing with the outer error or else every inner one.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn outer_err() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Err("outer") }
+    /// fn inner_err() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Ok(vec![Ok(1), Err("e1"), Err("e2")]) }
+    /// fn all_ok() -> Result<Vec<Result<i32, &'static str>>, &'static str> { Ok(vec![Ok(1), Ok(2)]) }
+    ///
+    /// assert_eq!(outer_err().flatten_vec_all(), Err(vec!["outer"]));
+    /// assert_eq!(inner_err().flatten_vec_all(), Err(vec!["e1", "e2"]));
+    /// assert_eq!(all_ok().flatten_vec_all(), Ok(vec![1, 2]));
+    /// ```
+    fn flatten_vec_all<B>(self) -> Result<Vec<B>, Vec<E>>
+    where
+        A: IntoIterator<Item = Result<B, E>>;
+
+    /// Passes the whole result to `f`, handing over to custom logic whenever no combinator fits while keeping a method chain.
+    ///
+    /// ```
+    /// use lifterr::result::ResultExt;
+    ///
+    /// fn summary(r: Result<i32, &str>) -> String {
+    ///     match r {
+    ///         Ok(n) => format!("got {n}"),
+    ///         Err(e) => format!("failed with {e}"),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!("42".parse::<i32>().map_err(|_| "nan").pipe(summary), "got 42");
+    /// assert_eq!("x".parse::<i32>().map_err(|_| "nan").pipe(summary), "failed with nan");
+    /// ```
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Result<A, E>) -> B;
+}
+
+impl<A, E> ResultExt<A, E> for Result<A, E> {
+    fn then<F, B>(self, f: F) -> Result<B, E>
+    where
+        F: FnOnce() -> Result<B, E>,
+    {
+        self.and_then(|_| f())
+    }
+
+    fn and_zip<B, F>(self, f: F) -> Result<(A, B), E>
+    where
+        F: FnOnce(&A) -> Result<B, E>,
+    {
+        self.and_then(|a| f(&a).map(|b| (a, b)))
+    }
+
+    fn combine<B, C, FE, FV>(self, other: Result<B, E>, merge_err: FE, merge_ok: FV) -> Result<C, E>
+    where
+        FE: FnOnce(E, E) -> E,
+        FV: FnOnce(A, B) -> C,
+    {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Ok(merge_ok(a, b)),
+            (Err(e1), Err(e2)) => Err(merge_err(e1, e2)),
+            (Err(e), Ok(_)) | (Ok(_), Err(e)) => Err(e),
+        }
+    }
+
+    fn then_err<F, H>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce() -> Result<A, H>,
+    {
+        self.or_else(|_| f())
+    }
+
+    fn wrap_err<W, F>(self, f: F) -> Result<A, W>
+    where
+        F: FnOnce(E) -> W,
+    {
+        self.map_err(f)
+    }
+
+    fn errs(self) -> Result<A, Vec<E>> {
+        self.map_err(|e| vec![e])
+    }
+
+    fn map_err_parts<X, Y, H, F>(self, f: F) -> Result<A, H>
+    where
+        E: Into<(X, Y)>,
+        F: FnOnce(X, Y) -> H,
+    {
+        self.map_err(|e| {
+            let (x, y) = e.into();
+            f(x, y)
+        })
+    }
+
+    fn try_map_err<H, F>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce(E) -> Result<H, H>,
+    {
+        self.map_err(|e| f(e).merge())
+    }
+
+    fn map_when<F>(self, cond: bool, f: F) -> Result<A, E>
+    where
+        F: FnOnce(A) -> A,
+    {
+        if cond {
+            self.map(f)
+        } else {
+            self
+        }
+    }
+
+    fn inspect<F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&A),
+    {
+        self.map(|a| {
+            f(&a);
+            a
+        })
+    }
+
+    fn inspect_err<F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&E),
+    {
+        self.map_err(|e| {
+            f(&e);
+            e
+        })
+    }
+
+    fn tap_dbg(self) -> Result<A, E>
+    where
+        A: Debug,
+        E: Debug,
+    {
+        if cfg!(debug_assertions) {
+            eprintln!("{:?}", self);
+        }
+        self
+    }
+
+    fn tap_elapsed<F>(self, start: Instant, f: F) -> Result<A, E>
+    where
+        F: FnOnce(Duration),
+    {
+        f(start.elapsed());
+        self
+    }
+
+    fn on_ok<F>(self, f: F)
+    where
+        F: FnOnce(A),
+    {
+        if let Ok(a) = self {
+            f(a)
+        }
+    }
+
+    fn on_err<F>(self, f: F)
+    where
+        F: FnOnce(E),
+    {
+        if let Err(e) = self {
+            f(e)
+        }
+    }
+
+    fn map_in_place<F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&mut A),
+    {
+        self.map(|mut a| {
+            f(&mut a);
+            a
+        })
+    }
+
+    fn map_err_in_place<F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&mut E),
+    {
+        self.map_err(|mut e| {
+            f(&mut e);
+            e
+        })
+    }
+
+    fn matches_ok<F>(self, pred: F) -> bool
+    where
+        F: FnOnce(&A) -> bool,
+    {
+        match self {
+            Ok(a) => pred(&a),
+            Err(_) => false,
+        }
+    }
+
+    fn matches_err<F>(self, pred: F) -> bool
+    where
+        F: FnOnce(&E) -> bool,
+    {
+        match self {
+            Ok(_) => false,
+            Err(e) => pred(&e),
+        }
+    }
+
+    fn ok_then<F>(self, f: F) -> bool
+    where
+        F: FnOnce(A) -> bool,
+    {
+        self.map_or(false, f)
+    }
+
+    fn err_then<F>(self, f: F) -> bool
+    where
+        F: FnOnce(E) -> bool,
+    {
+        self.err().map_or(false, f)
+    }
+
+    fn sort_into(self, oks: &mut Vec<A>, errs: &mut Vec<E>) -> bool {
+        match self {
+            Ok(a) => {
+                oks.push(a);
+                true
+            }
+            Err(e) => {
+                errs.push(e);
+                false
+            }
+        }
+    }
+
+    fn ok_if<F>(self, pred: F) -> Option<A>
+    where
+        F: FnOnce(&A) -> bool,
+    {
+        self.ok().filter(pred)
+    }
+
+    fn map_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(A) -> B,
+    {
+        self.map_or_else(|_| B::default(), f)
+    }
+
+    fn map_err_or_default<B, F>(self, f: F) -> B
+    where
+        B: Default,
+        F: FnOnce(E) -> B,
+    {
+        self.map_or_else(f, |_| B::default())
+    }
+
+    fn fold_ok<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, A) -> B,
+    {
+        match self {
+            Ok(a) => f(init, a),
+            Err(_) => init,
+        }
+    }
+
+    fn fold_err<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnOnce(B, E) -> B,
+    {
+        match self {
+            Ok(_) => init,
+            Err(e) => f(init, e),
+        }
+    }
+
+    fn swap(self) -> Result<E, A> {
+        match self {
+            Ok(o) => Err(o),
+            Err(e) => Ok(e),
+        }
+    }
+
+    fn dual_ref<B, H, F, G>(&self, f: F, g: G) -> Result<B, H>
+    where
+        F: FnOnce(&A) -> B,
+        G: FnOnce(&E) -> H,
+    {
+        self.as_ref().map(f).map_err(g)
+    }
+
+    fn map_same<B, F>(self, f: F) -> Result<B, B>
+    where
+        E: Into<A>,
+        F: FnOnce(A) -> B,
+    {
+        match self {
+            Ok(a) => Ok(f(a)),
+            Err(e) => Err(f(e.into())),
+        }
+    }
+
+    fn as_ok_ref(&self) -> Option<&A> {
+        self.as_ref().ok()
+    }
+
+    fn as_err_ref(&self) -> Option<&E> {
+        self.as_ref().err()
+    }
+
+    fn as_deref_ok(&self) -> Option<&A::Target>
+    where
+        A: Deref,
+    {
+        self.as_ref().ok().map(Deref::deref)
+    }
+
+    fn cloned_ok(self) -> Result<A::Target, E>
+    where
+        A: Deref,
+        A::Target: Clone,
+    {
+        self.map(|a| a.deref().clone())
+    }
+
+    fn cloned_err(self) -> Result<A, E::Target>
+    where
+        E: Deref,
+        E::Target: Clone,
+    {
+        self.map_err(|e| e.deref().clone())
+    }
+
+    fn ok_ref_and_then<B, F>(&self, f: F) -> Option<B>
+    where
+        F: FnOnce(&A) -> Option<B>,
+    {
+        self.as_ref().ok().and_then(f)
+    }
+
+    fn merge_ref<'a, T>(&'a self) -> T
+    where
+        A: 'a,
+        E: 'a,
+        T: From<&'a A> + From<&'a E>,
+    {
+        self.as_ref().merge()
+    }
+
+    fn into_control_flow(self) -> ControlFlow<E, A> {
+        match self {
+            Ok(a) => ControlFlow::Continue(a),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+
+    fn annotate_err<F, C>(self, f: F) -> Result<A, (E, C)>
+    where
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| (e, f()))
+    }
+
+    fn box_err(self) -> Result<A, Box<dyn Error + Send + Sync>>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.map_err(Into::into)
+    }
+
+    fn downcast_err<T>(self) -> Result<Result<A, T>, E>
+    where
+        E: sealed::BoxedError,
+        T: Error + 'static,
+    {
+        match self {
+            Ok(a) => Ok(Ok(a)),
+            Err(e) => e.downcast().map(Err),
+        }
+    }
+
+    #[cfg(feature = "anyhow")]
+    fn into_anyhow(self) -> anyhow::Result<A>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.map_err(anyhow::Error::new)
+    }
+
+    #[cfg(feature = "anyhow")]
+    fn context_anyhow<C>(self, ctx: C) -> anyhow::Result<A>
+    where
+        C: Display + Send + Sync + 'static,
+        E: Error + Send + Sync + 'static,
+    {
+        self.into_anyhow().map_err(|e| e.context(ctx))
+    }
+
+    #[track_caller]
+    fn err_with_location(self) -> Result<A, LocatedError<E>> {
+        let location = Location::caller();
+        self.map_err(|error| LocatedError { error, location })
+    }
+
+    fn push_context<C>(self, ctx: C) -> Result<A, Vec<String>>
+    where
+        C: Display,
+        E: Display,
+    {
+        self.map_err(|e| vec![ctx.to_string(), e.to_string()])
+    }
+
+    fn prepend_context<C>(self, ctx: C) -> Result<A, Vec<String>>
+    where
+        C: Display,
+        E: Into<Vec<String>>,
+    {
+        self.map_err(|e| {
+            let mut trail = e.into();
+            trail.insert(0, ctx.to_string());
+            trail
+        })
+    }
+
+    fn err_chain(self) -> Result<A, Vec<String>>
+    where
+        E: Error,
+    {
+        self.map_err(|e| {
+            iter::successors(Some(&e as &dyn Error), |&e| e.source())
+                .map(ToString::to_string)
+                .collect()
+        })
+    }
+
+    fn ok_iter(self) -> Flatten<IntoIter<A>>
+    where
+        A: IntoIterator,
+    {
+        self.into_iter().flatten()
+    }
+
+    #[cfg(feature = "either")]
+    fn ok_iter_or_err(
+        self,
+    ) -> Either<Map<A::IntoIter, fn(A::Item) -> Either<A::Item, E>>, Once<Either<A::Item, E>>>
+    where
+        A: IntoIterator,
+    {
+        match self {
+            Ok(a) => Either::Left(a.into_iter().map(Either::Left as fn(_) -> _)),
+            Err(e) => Either::Right(iter::once(Either::Right(e))),
+        }
+    }
+
+    #[cfg(feature = "future")]
+    fn ready(self) -> Ready<Result<A, E>> {
+        future::ready(self)
+    }
+
+    fn recover_with<F, H>(self, f: F) -> Result<A, H>
+    where
+        F: FnOnce(E) -> Result<A, H>,
+    {
+        self.map_or_else(f, A::into_ok)
+    }
+
+    fn recover_with_original<F, H>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(&E) -> Result<A, H>,
+    {
+        self.or_else(|e| f(&e).map_err(|_| e))
+    }
+
+    fn and_then_or<B, F, G>(self, f: F, g: G) -> Result<B, E>
+    where
+        F: FnOnce(A) -> Result<B, E>,
+        G: FnOnce(E) -> Result<B, E>,
+    {
+        self.map_or_else(g, f)
+    }
+
+    #[track_caller]
+    fn expect_ok(self, msg: &str) -> A
+    where
+        E: Debug,
+    {
+        match self {
+            Ok(a) => a,
+            Err(e) => panic!("{}: {:?}", msg, e),
+        }
+    }
+
+    #[track_caller]
+    fn expect_err(self, msg: &str) -> E
+    where
+        A: Debug,
+    {
+        match self {
+            Ok(a) => panic!("{}: {:?}", msg, a),
+            Err(e) => e,
+        }
+    }
+
+    fn expect_err_or<F>(self, f: F) -> E
+    where
+        F: FnOnce(A) -> E,
+    {
+        self.map_or_else(|e| e, f)
+    }
+
+    fn flatten_err_into<H>(self) -> Result<A::Ok, H>
+    where
+        A: sealed::Fallible,
+        H: From<E> + From<A::Err>,
+    {
+        self.map_err(H::from)
+            .and_then(|a| a.into_result().map_err(H::from))
+    }
+
+    fn flatten_with<F, G>(self, on_outer: F, on_inner: G) -> Result<A::Ok, E>
+    where
+        A: sealed::Fallible<Err = E>,
+        F: FnOnce(E) -> E,
+        G: FnOnce(E) -> E,
+    {
+        self.map_err(on_outer)
+            .and_then(|a| a.into_result().map_err(on_inner))
+    }
+
+    fn or_default_inner(self) -> Result<A::Inner, E>
+    where
+        A: sealed::Optional,
+        A::Inner: Default,
+    {
+        self.map(|a| a.into_option().unwrap_or_default())
+    }
+
+    fn flatten_vec<B>(self) -> Result<Vec<B>, E>
+    where
+        A: IntoIterator<Item = Result<B, E>>,
+    {
+        self.and_then(|a| a.into_iter().collect())
+    }
+
+    fn flatten_vec_all<B>(self) -> Result<Vec<B>, Vec<E>>
+    where
+        A: IntoIterator<Item = Result<B, E>>,
+    {
+        self.errs().and_then(|a| a.into_iter().traverse_all(|r| r))
+    }
+
+    fn pipe<B, F>(self, f: F) -> B
+    where
+        F: FnOnce(Result<A, E>) -> B,
+    {
+        f(self)
+    }
+}
+
+/// Error paired with the location where it was captured, created by [`ResultExt::err_with_location`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedError<E> {
+    error: E,
+    location: &'static Location<'static>,
+}
+
+impl<E> LocatedError<E> {
+    /// Gets a reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Gets the location where the error was captured.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Unwraps the underlying error, discarding its location.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for LocatedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.error, self.location)
+    }
+}
+
+impl<E: Error + 'static> Error for LocatedError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Turns `ControlFlow::Continue(a)` into `Ok(a)` or `ControlFlow::Break(e)` into `Err(e)`.
+///
+/// ```
+/// use lifterr::result::from_control_flow;
+/// use std::ops::ControlFlow;
+///
+/// assert_eq!(from_control_flow(ControlFlow::<&str, _>::Continue(1)), Ok(1));
+/// assert_eq!(from_control_flow(ControlFlow::<_, i32>::Break("e")), Err("e"));
+/// ```
+pub fn from_control_flow<A, E>(flow: ControlFlow<E, A>) -> Result<A, E> {
+    match flow {
+        ControlFlow::Continue(a) => Ok(a),
+        ControlFlow::Break(e) => Err(e),
+    }
+}
+
+/// Runs the fallible `f` `n` times, collecting every success or bailing out with the first error.
+///
+/// ```
+/// use lifterr::result::repeat;
+///
+/// let mut calls = 0;
+/// let mut next = || { calls += 1; if calls < 3 { Ok(calls) } else { Err("exhausted") } };
+///
+/// assert_eq!(repeat(2, &mut next), Ok(vec![1, 2]));
+/// assert_eq!(repeat(2, &mut next), Err("exhausted"));
+/// assert_eq!(calls, 3);
+/// ```
+pub fn repeat<A, E, F>(n: usize, f: F) -> Result<Vec<A>, E>
+where
+    F: FnMut() -> Result<A, E>,
+{
+    std::iter::repeat_with(f).take(n).collect()
+}
+
+/// Tries each fallible source in order, yielding the first success or every error when all of them fail.
+///
+/// ```
+/// use lifterr::result::first_ok_of;
+///
+/// type Source = Box<dyn FnOnce() -> Result<i32, &'static str>>;
+///
+/// let sources: Vec<Source> = vec![
+///     Box::new(|| Err("primary")),
+///     Box::new(|| Ok(42)),
+///     Box::new(|| unreachable!()),
+/// ];
+/// assert_eq!(first_ok_of(sources), Ok(42));
+///
+/// let sources: Vec<Source> = vec![Box::new(|| Err("primary")), Box::new(|| Err("secondary"))];
+/// assert_eq!(first_ok_of(sources), Err(vec!["primary", "secondary"]));
+/// ```
+pub fn first_ok_of<A, E>(sources: Vec<Box<dyn FnOnce() -> Result<A, E>>>) -> Result<A, Vec<E>> {
+    let mut errs = Vec::new();
+    for source in sources {
+        match source() {
+            Ok(a) => return Ok(a),
+            Err(e) => errs.push(e),
+        }
+    }
+    Err(errs)
+}
+
+/// Runs the fallible `f`, pairing its result with how long it took regardless of the outcome.
+///
+/// ```
+/// use lifterr::result::timed;
+/// use std::time::Duration;
+///
+/// let (r, elapsed) = timed(|| "42".parse::<i32>());
+/// assert_eq!(r, Ok(42));
+/// assert!(elapsed >= Duration::ZERO);
+///
+/// let (r, _) = timed(|| Err::<i32, _>("e"));
+/// assert_eq!(r, Err("e"));
+/// ```
+pub fn timed<A, E, F>(f: F) -> (Result<A, E>, Duration)
+where
+    F: FnOnce() -> Result<A, E>,
+{
+    let start = Instant::now();
+    let r = f();
+    (r, start.elapsed())
+}
+
+/// Accumulator of results pushed one at a time, keeping every success and every error apart.
+///
+/// ```
+/// use lifterr::result::ResultAccumulator;
+///
+/// let mut mixed = ResultAccumulator::new();
+/// mixed.push(Ok(1));
+/// mixed.push(Err("e1"));
+/// mixed.push(Ok(3));
+/// mixed.push(Err("e2"));
+/// assert_eq!(mixed.finish(), Err(vec!["e1", "e2"]));
+///
+/// let mut all_ok = ResultAccumulator::<_, &str>::new();
+/// all_ok.push(Ok(1));
+/// all_ok.push(Ok(2));
+/// assert_eq!(all_ok.finish(), Ok(vec![1, 2]));
+///
+/// assert_eq!(ResultAccumulator::<i32, &str>::new().finish(), Ok(vec![]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResultAccumulator<A, E> {
+    oks: Vec<A>,
+    errs: Vec<E>,
+}
+
+impl<A, E> ResultAccumulator<A, E> {
+    /// Creates an accumulator without any results.
+    pub fn new() -> Self {
+        ResultAccumulator {
+            oks: Vec::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    /// Keeps `A` if `Ok(a)` or `E` if `Err(e)`.
+    pub fn push(&mut self, item: Result<A, E>) {
+        item.sort_into(&mut self.oks, &mut self.errs);
+    }
+
+    /// Yields every success accumulated when there were no errors, otherwise every error.
+    pub fn finish(self) -> Result<Vec<A>, Vec<E>> {
+        if self.errs.is_empty() {
+            Ok(self.oks)
+        } else {
+            Err(self.errs)
+        }
+    }
+}
+
+impl<A, E> Default for ResultAccumulator<A, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Extension with a set of sequencing combinators for `Vec<Result<A, E>>`.
+pub trait SequenceExt<A, E> {
+    /// Turns a vector of results into a result of a vector, failing with the first error found.
+    ///
+    /// ```
+    /// use lifterr::result::SequenceExt;
+    ///
+    /// assert_eq!(vec![Ok::<_, &str>(1), Ok(2)].sequence(), Ok(vec![1, 2]));
+    /// assert_eq!(vec![Ok(1), Err("e1"), Err("e2")].sequence(), Err("e1"));
+    /// ```
+    fn sequence(self) -> Result<Vec<A>, E>;
+
+    /// Turns a vector of results into a result of a vector, accumulating every error found.
+    ///
+    /// ```
+    /// use lifterr::result::SequenceExt;
+    ///
+    /// assert_eq!(vec![Ok::<_, &str>(1), Ok(2)].sequence_all(), Ok(vec![1, 2]));
+    /// assert_eq!(vec![Ok(1), Err("e1"), Err("e2")].sequence_all(), Err(vec!["e1", "e2"]));
+    /// ```
+    fn sequence_all(self) -> Result<Vec<A>, Vec<E>>;
+}
+
+impl<A, E> SequenceExt<A, E> for Vec<Result<A, E>> {
+    fn sequence(self) -> Result<Vec<A>, E> {
+        self.into_iter().collect()
+    }
+
+    fn sequence_all(self) -> Result<Vec<A>, Vec<E>> {
+        self.into_iter().traverse_all(|r| r)
+    }
+}
+
+/// Ability to merge branches of a `Result<A, E>` when `A` and `E` are compatible (e.g. when they unify under an `Into<T>` conversion).
+pub trait Merge<T> {
+    /// Merges both branches of a result, giving preference to the `Ok` branch when needed.
+    ///
+    /// ```
+    /// use lifterr::result::Merge;
+    ///
+    /// fn merge_ok() -> i32 {
+    ///     Ok::<_, i32>(42).merge()
+    /// }
+    ///
+    /// fn merge_err() -> i32 {
+    ///     Err::<i32, _>(42).merge()
+    /// }
+    ///
+    /// assert_eq!(merge_ok(), 42);
+    /// assert_eq!(merge_err(), 42);
+    /// ```
+    fn merge(self) -> T;
+}
+
+impl<A, E, T> Merge<T> for Result<A, E>
+where
+    T: From<A> + From<E>,
+{
+    fn merge(self) -> T {
+        self.map_or_else(T::from, T::from)
+    }
+}
+
+/// Lifter of values into successful results.
+pub trait IntoOk<O> {
+    /// Lifts a value of type `O` into a `Result<O, E>` by wrapping it into an `Ok`.
+    ///
+    /// ```
+    /// use lifterr::result::IntoOk;
+    ///
+    /// assert_eq!(42.into_ok::<&'static str>(), Ok(42));
+    /// ```
+    fn into_ok<E>(self) -> Result<O, E>;
+}
+
+impl<O> IntoOk<O> for O {
+    fn into_ok<E>(self) -> Result<O, E> {
+        Ok(self)
+    }
+}
+
+/// Lifter of values into failed results.
+pub trait IntoErr<E> {
+    /// Lifts a value of type `E` into a `Result<O, E>` by wrapping it into an `Err`.
+    ///
+    /// ```
+    /// use lifterr::result::IntoErr;
+    ///
+    /// assert_eq!("e".into_err::<i32>(), Err("e"));
+    /// ```
+    fn into_err<O>(self) -> Result<O, E>;
+}
+
+impl<E> IntoErr<E> for E {
+    fn into_err<O>(self) -> Result<O, E> {
+        Err(self)
+    }
+}
+
+pub(crate) mod sealed {
+    use std::error::Error;
+
+    pub trait BoxedError: Sized {
+        fn downcast<T>(self) -> Result<T, Self>
+        where
+            T: Error + 'static;
+    }
+
+    impl BoxedError for Box<dyn Error> {
+        fn downcast<T>(self) -> Result<T, Self>
+        where
+            T: Error + 'static,
+        {
+            self.downcast().map(|t| *t)
+        }
+    }
+
+    impl BoxedError for Box<dyn Error + Send + Sync> {
+        fn downcast<T>(self) -> Result<T, Self>
+        where
+            T: Error + 'static,
+        {
+            self.downcast().map(|t| *t)
+        }
+    }
+
+    pub trait Fallible {
+        type Ok;
+        type Err;
+
+        fn into_result(self) -> Result<Self::Ok, Self::Err>;
+    }
+
+    impl<A, E> Fallible for Result<A, E> {
+        type Ok = A;
+        type Err = E;
+
+        fn into_result(self) -> Result<A, E> {
+            self
+        }
+    }
+
+    pub trait Optional {
+        type Inner;
+
+        fn into_option(self) -> Option<Self::Inner>;
+    }
+
+    impl<A> Optional for Option<A> {
+        type Inner = A;
+
+        fn into_option(self) -> Option<A> {
+            self
+        }
     }
 }
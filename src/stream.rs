@@ -0,0 +1,172 @@
+//! Extra utilities for handling failures in streams.
+//!
+//! This module extends capabilities offered by [`futures_core::stream`] and is only available with the `futures`
+//! feature enabled.
+
+use futures_core::stream::{Stream, TryStream};
+use pin_project_lite::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Extension with a set of extra combinators for `TryStream<Ok = A, Error = E>`.
+pub trait TryStreamExt: TryStream {
+    /// Recovers from every error item with a fallible function, possibly remapping to a different error of type `H`.
+    ///
+    /// ```
+    /// use lifterr::stream::TryStreamExt;
+    /// # use futures_core::Stream;
+    /// # use std::pin::Pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// #
+    /// # struct Iter<I>(I);
+    /// #
+    /// # impl<I: Iterator + Unpin> Stream for Iter<I> {
+    /// #     type Item = I::Item;
+    /// #
+    /// #     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+    /// #         Poll::Ready(self.0.next())
+    /// #     }
+    /// # }
+    /// #
+    /// # fn stream_of<A: Unpin>(items: Vec<A>) -> Iter<std::vec::IntoIter<A>> { Iter(items.into_iter()) }
+    /// #
+    /// # fn collect_now<S: Stream>(stream: S) -> Vec<S::Item> {
+    /// #     let mut stream = Box::pin(stream);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     let mut items = Vec::new();
+    /// #     while let Poll::Ready(Some(item)) = stream.as_mut().poll_next(&mut cx) {
+    /// #         items.push(item);
+    /// #     }
+    /// #     items
+    /// # }
+    /// #
+    /// let mut recoveries = 0;
+    /// let items = stream_of(vec![Ok(1), Err("fine"), Ok(3), Err("bad")]).recover_with_stream(|e| {
+    ///     recoveries += 1;
+    ///     if e == "fine" { Ok(0) } else { Err("not fine") }
+    /// });
+    ///
+    /// assert_eq!(collect_now(items), vec![Ok(1), Ok(0), Ok(3), Err("not fine")]);
+    /// assert_eq!(recoveries, 2);
+    /// ```
+    fn recover_with_stream<F, H>(self, f: F) -> RecoverWithStream<Self, F>
+    where
+        F: FnMut(Self::Error) -> Result<Self::Ok, H>,
+        Self: Sized,
+    {
+        RecoverWithStream { stream: self, f }
+    }
+
+    /// Runs `f` with a reference to every error item, passing all items through.
+    ///
+    /// ```
+    /// use lifterr::stream::TryStreamExt;
+    /// # use futures_core::Stream;
+    /// # use std::pin::Pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// #
+    /// # struct Iter<I>(I);
+    /// #
+    /// # impl<I: Iterator + Unpin> Stream for Iter<I> {
+    /// #     type Item = I::Item;
+    /// #
+    /// #     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<I::Item>> {
+    /// #         Poll::Ready(self.0.next())
+    /// #     }
+    /// # }
+    /// #
+    /// # fn stream_of<A: Unpin>(items: Vec<A>) -> Iter<std::vec::IntoIter<A>> { Iter(items.into_iter()) }
+    /// #
+    /// # fn collect_now<S: Stream>(stream: S) -> Vec<S::Item> {
+    /// #     let mut stream = Box::pin(stream);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     let mut items = Vec::new();
+    /// #     while let Poll::Ready(Some(item)) = stream.as_mut().poll_next(&mut cx) {
+    /// #         items.push(item);
+    /// #     }
+    /// #     items
+    /// # }
+    /// #
+    /// let mut errors = Vec::new();
+    /// let items = stream_of(vec![Ok(1), Err("e1"), Ok(3), Err("e2")]).inspect_err_stream(|e| errors.push(*e));
+    ///
+    /// assert_eq!(collect_now(items), vec![Ok(1), Err("e1"), Ok(3), Err("e2")]);
+    /// assert_eq!(errors, vec!["e1", "e2"]);
+    /// ```
+    fn inspect_err_stream<F>(self, f: F) -> InspectErrStream<Self, F>
+    where
+        F: FnMut(&Self::Error),
+        Self: Sized,
+    {
+        InspectErrStream { stream: self, f }
+    }
+}
+
+impl<S: TryStream> TryStreamExt for S {}
+
+pin_project! {
+    /// Stream recovering from error items, created by [`TryStreamExt::recover_with_stream`].
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RecoverWithStream<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+    }
+}
+
+impl<St, F, H> Stream for RecoverWithStream<St, F>
+where
+    St: TryStream,
+    F: FnMut(St::Error) -> Result<St::Ok, H>,
+{
+    type Item = Result<St::Ok, H>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream
+            .try_poll_next(cx)
+            .map(|item| item.map(|r| r.or_else(f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    /// Stream inspecting error items, created by [`TryStreamExt::inspect_err_stream`].
+    #[derive(Debug)]
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InspectErrStream<St, F> {
+        #[pin]
+        stream: St,
+        f: F,
+    }
+}
+
+impl<St, F> Stream for InspectErrStream<St, F>
+where
+    St: TryStream,
+    F: FnMut(&St::Error),
+{
+    type Item = Result<St::Ok, St::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let f = this.f;
+        this.stream.try_poll_next(cx).map(|item| {
+            item.map(|r| {
+                if let Err(e) = &r {
+                    f(e);
+                }
+                r
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -0,0 +1,140 @@
+//! Extra utilities for accumulating validation failures.
+//!
+//! Whereas `?` and the combinators in [`crate::result`] stop at the first failure, the types in this module keep
+//! going so as to report every failure at once.
+
+/// Builder of values out of several independently validated fields, accumulating every error found along the way.
+///
+/// Up to twelve fields can be gathered before building.
+///
+/// ```
+/// use lifterr::validated::ValidatedBuilder;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User { name: String, age: u8 }
+///
+/// fn name(s: &str) -> Result<String, String> {
+///     if s.is_empty() { Err("name is empty".to_string()) } else { Ok(s.to_string()) }
+/// }
+///
+/// fn age(s: &str) -> Result<u8, String> {
+///     s.parse().map_err(|_| format!("{s} is not an age"))
+/// }
+///
+/// fn user(n: &str, a: &str) -> Result<User, Vec<String>> {
+///     ValidatedBuilder::new()
+///         .field(name(n))
+///         .field(age(a))
+///         .build(|name, age| User { name, age })
+/// }
+///
+/// assert_eq!(user("bob", "42"), Ok(User { name: "bob".to_string(), age: 42 }));
+/// assert_eq!(
+///     user("", "old"),
+///     Err(vec!["name is empty".to_string(), "old is not an age".to_string()])
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidatedBuilder<T, E> {
+    values: Option<T>,
+    errors: Vec<E>,
+}
+
+impl<E> ValidatedBuilder<(), E> {
+    /// Creates a builder without any fields.
+    pub fn new() -> Self {
+        ValidatedBuilder {
+            values: Some(()),
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<E> Default for ValidatedBuilder<(), E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> ValidatedBuilder<T, E> {
+    /// Gathers the next field, keeping its value if `Ok(b)` or accumulating its error if `Err(e)`.
+    pub fn field<B>(mut self, field: Result<B, E>) -> ValidatedBuilder<T::Output, E>
+    where
+        T: sealed::Append<B>,
+    {
+        let values = match field {
+            Ok(b) => self.values.map(|t| t.append(b)),
+            Err(e) => {
+                self.errors.push(e);
+                None
+            }
+        };
+        ValidatedBuilder {
+            values,
+            errors: self.errors,
+        }
+    }
+
+    /// Builds the value by applying `f` to every gathered field when all of them are valid, otherwise fails with every
+    /// error accumulated.
+    pub fn build<F, R>(self, f: F) -> Result<R, Vec<E>>
+    where
+        T: sealed::Apply<F, R>,
+    {
+        match self.values {
+            Some(t) if self.errors.is_empty() => Ok(t.apply(f)),
+            _ => Err(self.errors),
+        }
+    }
+}
+
+mod sealed {
+    pub trait Append<B> {
+        type Output;
+
+        fn append(self, b: B) -> Self::Output;
+    }
+
+    pub trait Apply<F, R> {
+        fn apply(self, f: F) -> R;
+    }
+
+    macro_rules! tuple_impls {
+        ($($t:ident)*) => {
+            impl<$($t,)* B> Append<B> for ($($t,)*) {
+                type Output = ($($t,)* B,);
+
+                #[allow(non_snake_case)]
+                fn append(self, b: B) -> Self::Output {
+                    let ($($t,)*) = self;
+                    ($($t,)* b,)
+                }
+            }
+
+            impl<F, R, $($t,)*> Apply<F, R> for ($($t,)*)
+            where
+                F: FnOnce($($t),*) -> R,
+            {
+                #[allow(non_snake_case)]
+                fn apply(self, f: F) -> R {
+                    let ($($t,)*) = self;
+                    f($($t),*)
+                }
+            }
+        };
+    }
+
+    tuple_impls!();
+    tuple_impls!(T1);
+    tuple_impls!(T1 T2);
+    tuple_impls!(T1 T2 T3);
+    tuple_impls!(T1 T2 T3 T4);
+    tuple_impls!(T1 T2 T3 T4 T5);
+    tuple_impls!(T1 T2 T3 T4 T5 T6);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7 T8);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7 T8 T9);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+    tuple_impls!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
+}